@@ -0,0 +1,221 @@
+//! The `#[proof]` attribute and `why_not!`. See `sqrt_irrational::attr` and
+//! `sqrt_irrational::why_not` for how to use them.
+
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Group, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Turn an empty function into a proof of its signature.
+///
+/// Rustc checks a function's return type and `where` clause where the function is defined,
+/// whether or not anything calls it, so the signature is the statement, and errors about it
+/// point at the function. The body has to be `{}`, and gets replaced with one that never runs.
+#[proc_macro_attribute]
+pub fn proof(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if let Some(arg) = attr.into_iter().next() {
+        return error("`#[proof]` doesn't take any arguments", arg.span());
+    }
+    let mut tokens: Vec<TokenTree> = item.into_iter().collect();
+    let is_fn = tokens.iter().any(|t| match t {
+        TokenTree::Ident(i) => i.to_string() == "fn",
+        _ => false,
+    });
+    if !is_fn {
+        return error("`#[proof]` goes on a function", Span::call_site());
+    }
+    match tokens.last() {
+        Some(TokenTree::Group(body)) if body.delimiter() == Delimiter::Brace => {
+            if !body.stream().is_empty() {
+                return error("a `#[proof]` function's body should be empty: the signature is the proof", body.span());
+            }
+        }
+        _ => return error("a `#[proof]` function needs a body, even if it's just `{}`", Span::call_site()),
+    }
+    tokens.pop();
+
+    let mut out: TokenStream = "#[allow(dead_code, unused_variables)]".parse().unwrap();
+    out.extend(tokens);
+    out.extend("{ ::core::unreachable!(\"`#[proof]` functions are only there to be type-checked\") }"
+        .parse::<TokenStream>()
+        .unwrap());
+    out
+}
+
+/// Check a type one piece at a time, smallest first, so the first error is about the
+/// piece that actually fails rather than everything built on top of it.
+///
+/// Every qualified projection `<T as Trait<..>>::Assoc`, generic path `Name<..>` and tuple
+/// in the type gets its own `const _: Option<..> = None;`, in the order a reader would
+/// have to check them by hand: arguments before whatever they're arguments to.
+#[proc_macro]
+pub fn why_not(input: TokenStream) -> TokenStream {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    if tokens.is_empty() {
+        return error("`why_not!` needs a type to check", Span::call_site());
+    }
+    let mut pieces = Vec::new();
+    let mut parser = Parser { tokens: &tokens, pos: 0, pieces: &mut pieces };
+    if let Err(span) = parser.ty() {
+        return error("`why_not!` can't take this type apart", span);
+    }
+    if parser.pos < tokens.len() {
+        return error("`why_not!` takes a single type", tokens[parser.pos].span());
+    }
+    // The same piece can turn up more than once, and once is enough to check it.
+    let mut seen: Vec<String> = Vec::new();
+    let mut out = TokenStream::new();
+    for piece in pieces {
+        let text: TokenStream = piece.iter().cloned().collect();
+        let text = text.to_string();
+        if seen.contains(&text) {
+            continue;
+        }
+        seen.push(text);
+        out.extend("const _: ::core::option::Option<".parse::<TokenStream>().unwrap());
+        out.extend(piece);
+        out.extend("> = ::core::option::Option::None;".parse::<TokenStream>().unwrap());
+    }
+    out
+}
+
+// Just enough of Rust's type grammar for the types proofs are made of: qualified
+// projections, paths with generic arguments (and associated type bindings), and tuples.
+// Each composite piece's tokens are recorded after its own pieces.
+struct Parser<'a> {
+    tokens: &'a [TokenTree],
+    pos: usize,
+    pieces: &'a mut Vec<Vec<TokenTree>>,
+}
+impl Parser<'_> {
+    fn peek(&self, offset: usize) -> Option<&TokenTree> {
+        self.tokens.get(self.pos + offset)
+    }
+    fn is_punct(&self, offset: usize, c: char) -> bool {
+        matches!(self.peek(offset), Some(TokenTree::Punct(p)) if p.as_char() == c)
+    }
+    fn is_ident(&self, offset: usize, name: &str) -> bool {
+        matches!(self.peek(offset), Some(TokenTree::Ident(i)) if i.to_string() == name)
+    }
+    fn is_path_sep(&self) -> bool {
+        self.is_punct(0, ':') && self.is_punct(1, ':')
+    }
+    // Where to point when something isn't what we expected.
+    fn span(&self) -> Span {
+        self.peek(0).or(self.tokens.last()).map_or(Span::call_site(), |t| t.span())
+    }
+    fn expect_punct(&mut self, c: char) -> Result<(), Span> {
+        if self.is_punct(0, c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.span())
+        }
+    }
+
+    fn ty(&mut self) -> Result<(), Span> {
+        let start = self.pos;
+        let composite = if self.is_punct(0, '<') {
+            // `<T as Trait<..>>::Assoc`
+            self.pos += 1;
+            self.ty()?;
+            if !self.is_ident(0, "as") {
+                return Err(self.span());
+            }
+            self.pos += 1;
+            // The trait isn't a type, but its arguments are.
+            self.path()?;
+            self.expect_punct('>')?;
+            if !self.is_path_sep() {
+                return Err(self.span());
+            }
+            while self.is_path_sep() {
+                self.pos += 2;
+                match self.peek(0) {
+                    Some(TokenTree::Ident(_)) => self.pos += 1,
+                    _ => return Err(self.span()),
+                }
+            }
+            true
+        } else if let Some(TokenTree::Group(group)) = self.peek(0) {
+            if group.delimiter() != Delimiter::Parenthesis {
+                return Err(group.span());
+            }
+            // A tuple, or a type in parentheses.
+            let inner: Vec<TokenTree> = group.stream().into_iter().collect();
+            let mut parser = Parser { tokens: &inner, pos: 0, pieces: &mut *self.pieces };
+            while parser.pos < inner.len() {
+                parser.ty()?;
+                if parser.pos < inner.len() {
+                    parser.expect_punct(',')?;
+                }
+            }
+            self.pos += 1;
+            !inner.is_empty()
+        } else {
+            self.path()?
+        };
+        if composite {
+            self.pieces.push(self.tokens[start..self.pos].to_vec());
+        }
+        Ok(())
+    }
+
+    // `a::b::Name<..>`. Returns whether there were any generic arguments.
+    fn path(&mut self) -> Result<bool, Span> {
+        let mut generic = false;
+        if self.is_path_sep() {
+            self.pos += 2;
+        }
+        loop {
+            match self.peek(0) {
+                Some(TokenTree::Ident(_)) => self.pos += 1,
+                _ => return Err(self.span()),
+            }
+            if self.is_punct(0, '<') {
+                self.pos += 1;
+                self.generic_args()?;
+                generic = true;
+            }
+            if !self.is_path_sep() {
+                return Ok(generic);
+            }
+            self.pos += 2;
+        }
+    }
+
+    // Everything after a `<`, up to and including the matching `>`.
+    fn generic_args(&mut self) -> Result<(), Span> {
+        loop {
+            if self.is_punct(0, '>') {
+                self.pos += 1;
+                return Ok(());
+            }
+            // `Output = T`
+            if matches!(self.peek(0), Some(TokenTree::Ident(_))) && self.is_punct(1, '=') {
+                self.pos += 2;
+            }
+            self.ty()?;
+            if self.is_punct(0, ',') {
+                self.pos += 1;
+            } else {
+                return self.expect_punct('>');
+            }
+        }
+    }
+}
+
+// `::core::compile_error!("...");`, pointing at `span`.
+fn error(message: &str, span: Span) -> TokenStream {
+    let mut message = Literal::string(message);
+    message.set_span(span);
+    let mut tokens: Vec<TokenTree> = "::core::compile_error!".parse::<TokenStream>().unwrap().into_iter().collect();
+    tokens.push(Group::new(Delimiter::Parenthesis, TokenTree::from(message).into()).into());
+    tokens.push(Punct::new(';', Spacing::Alone).into());
+    tokens
+        .into_iter()
+        .map(|mut t| {
+            t.set_span(span);
+            t
+        })
+        .collect()
+}
@@ -0,0 +1,21 @@
+//! Building this crate builds `sqrt_irrational` with no default features,
+//! which checks every proof in it without `std` or `alloc`.
+//!
+//! It also checks that the prelude is enough to write proofs of your own.
+#![no_std]
+
+use sqrt_irrational::prelude::*;
+
+proofs! {
+    type Ten = Successor<Nine>;
+    Equal<Ten, <Five as Product<Two>>::Output>;
+    Equal<Two, <Ten as Gcd<Four>>::Output>;
+    <Three as LessThan<Ten>>::Output;
+    Equal<Five, match_ord!(<Ten as Compare<Five>>::Output => { Less => One, EqualOrd => Two, Greater => Five })>;
+    Equal<Ratio<Six, Eight>, <Ratio<Three, Four> as RatioMul<Ratio<Two, Two>>>::Output>;
+}
+
+/// Lists reify without allocating, too.
+pub fn primes_below_ten() -> impl Iterator<Item = u64> {
+    <TCons<Two, TCons<Three, TCons<Five, TCons<Seven, TNil>>>> as ReifyList>::iter()
+}
@@ -0,0 +1,137 @@
+//! Naturals in binary, least significant bit first.
+//!
+//! A Peano natural is as deep as it is big, which is what makes big numbers
+//! so slow to check. These are only as deep as their number of bits.
+
+use ::core::marker::PhantomData;
+
+use crate::nat::*;
+use crate::proof::Equal;
+
+pub trait BinNat {}
+/// Binary zero.
+#[derive(Default)]
+pub struct BZero;
+impl BinNat for BZero {}
+/// `2n`.
+#[derive(Default)]
+pub struct PushZero<N: BinNat> { _n: PhantomData<N> }
+impl<N: BinNat> BinNat for PushZero<N> {}
+/// `2n + 1`.
+#[derive(Default)]
+pub struct PushOne<N: BinNat> { _n: PhantomData<N> }
+impl<N: BinNat> BinNat for PushOne<N> {}
+
+impl Reify for BZero {
+    const OUTPUT: u64 = 0;
+}
+impl<N: BinNat + Reify> Reify for PushZero<N> {
+    const OUTPUT: u64 = 2 * N::OUTPUT;
+}
+impl<N: BinNat + Reify> Reify for PushOne<N> {
+    const OUTPUT: u64 = 2 * N::OUTPUT + 1;
+}
+
+/// The Peano natural with the same value.
+pub trait ToPeano {
+    type Output: Nat;
+}
+impl ToPeano for BZero {
+    type Output = Zero;
+}
+impl<N: BinNat + ToPeano> ToPeano for PushZero<N> where
+    <N as ToPeano>::Output: Sum<<N as ToPeano>::Output>,
+    <<N as ToPeano>::Output as Sum<<N as ToPeano>::Output>>::Output: Nat,
+{
+    type Output = <<N as ToPeano>::Output as Sum<<N as ToPeano>::Output>>::Output;
+}
+impl<N: BinNat + ToPeano> ToPeano for PushOne<N> where
+    <N as ToPeano>::Output: Sum<<N as ToPeano>::Output>,
+    <<N as ToPeano>::Output as Sum<<N as ToPeano>::Output>>::Output: Nat,
+{
+    type Output = Successor<<<N as ToPeano>::Output as Sum<<N as ToPeano>::Output>>::Output>;
+}
+
+/// `n + 1`, carrying as far as it needs to.
+pub trait Increment: BinNat {
+    type Output: BinNat;
+}
+impl Increment for BZero {
+    type Output = PushOne<BZero>;
+}
+impl<N: BinNat> Increment for PushZero<N> {
+    type Output = PushOne<N>;
+}
+impl<N: Increment> Increment for PushOne<N> {
+    type Output = PushZero<<N as Increment>::Output>;
+}
+
+/// The binary natural with the same value as a Peano one, counted up one `Increment` at a
+/// time, so it never has leading zeros. See `BinaryRoundTrip` for the proof that it's the same value.
+pub trait ToBinary: Nat {
+    type Output: BinNat;
+}
+impl ToBinary for Zero {
+    type Output = BZero;
+}
+impl<N: ToBinary> ToBinary for Successor<N> where <N as ToBinary>::Output: Increment {
+    type Output = <<N as ToBinary>::Output as Increment>::Output;
+}
+
+// The bottom bit is right there, so there's no need to divide anything.
+impl Parity for BZero {
+    type IsEven = True;
+}
+impl<N: BinNat> Parity for PushZero<N> {
+    type IsEven = True;
+}
+impl<N: BinNat> Parity for PushOne<N> {
+    type IsEven = False;
+}
+
+// The shortcut agrees with `Remainder<Two>` on the Peano side.
+proofs! {
+    type BOne = PushOne<BZero>;
+    type BTwo = PushZero<BOne>;
+    type BThree = PushOne<BOne>;
+    type BFour = PushZero<BTwo>;
+    type BFive = PushOne<BTwo>;
+    type BSix = PushZero<BThree>;
+    type BSeven = PushOne<BThree>;
+    type BEight = PushZero<BFour>;
+    type BNine = PushOne<BFour>;
+    Equal<Nine, <BNine as ToPeano>::Output>;
+    Equal<Six, <BSix as ToPeano>::Output>;
+    // A leading zero doesn't change anything.
+    Equal<Zero, <PushZero<BZero> as ToPeano>::Output>;
+
+    Equal<BZero, <Zero as ToBinary>::Output>;
+    Equal<BOne, <One as ToBinary>::Output>;
+    Equal<BFour, <Four as ToBinary>::Output>;
+    Equal<BSeven, <Seven as ToBinary>::Output>;
+    Equal<BEight, <BSeven as Increment>::Output>;
+    Equal<BNine, <Nine as ToBinary>::Output>;
+    // There and back, both ways round.
+    Equal<Six, <<Six as ToBinary>::Output as ToPeano>::Output>;
+    Equal<BFive, <<BFive as ToPeano>::Output as ToBinary>::Output>;
+
+    Equal<IsEven<BZero>, IsEven<<BZero as ToPeano>::Output>>;
+    Equal<IsEven<BOne>, IsEven<<BOne as ToPeano>::Output>>;
+    Equal<IsEven<BTwo>, IsEven<<BTwo as ToPeano>::Output>>;
+    Equal<IsEven<BThree>, IsEven<<BThree as ToPeano>::Output>>;
+    Equal<IsEven<BFour>, IsEven<<BFour as ToPeano>::Output>>;
+    Equal<IsEven<BFive>, IsEven<<BFive as ToPeano>::Output>>;
+    Equal<IsEven<BSix>, IsEven<<BSix as ToPeano>::Output>>;
+    Equal<IsEven<BSeven>, IsEven<<BSeven as ToPeano>::Output>>;
+    Equal<IsEven<BEight>, IsEven<<BEight as ToPeano>::Output>>;
+    Equal<IsEven<BNine>, IsEven<<BNine as ToPeano>::Output>>;
+}
+static_assert_nat_eq!(PushOne<PushZero<PushOne<BZero>>>, Five);
+
+// Converting keeps the value.
+static_assert_nat_eq!(<Zero as ToBinary>::Output, Zero);
+static_assert_nat_eq!(<Three as ToBinary>::Output, Three);
+static_assert_nat_eq!(<Eight as ToBinary>::Output, Eight);
+static_assert_nat_eq!(<<Five as Square>::Output as ToBinary>::Output, <Five as Square>::Output);
+static_assert_nat_eq!(<<Eight as Square>::Output as ToBinary>::Output, <Eight as Square>::Output);
+static_assert_nat_eq!(<PushOne<PushOne<PushZero<PushOne<PushOne<BZero>>>>> as ToPeano>::Output, PushOne<PushOne<PushZero<PushOne<PushOne<BZero>>>>>);
@@ -0,0 +1,67 @@
+//! Naturals as const generics, for meeting APIs that use `const N: usize`.
+//!
+//! Stable Rust can't do arithmetic on a const parameter in a type, so there's no
+//! getting from `ConstNat<N>` to `ConstNat<{ N - 1 }>` and recursing. Instead every value
+//! up to `CONST_LIMIT` gets its own pair of impls, written out by a macro.
+
+use crate::binary::ToPeano;
+use crate::nat::*;
+use crate::proof::Equal;
+
+/// The biggest `N` that `ConstNat<N>` converts to and from.
+pub const CONST_LIMIT: usize = 64;
+
+/// `N`, as a type.
+#[derive(Default, Clone, Copy)]
+pub struct ConstNat<const N: usize>;
+impl<const N: usize> Reify for ConstNat<N> {
+    const OUTPUT: u64 = N as u64;
+}
+
+/// The `ConstNat` with the same value as a Peano natural.
+pub trait FromPeano {
+    type Output;
+}
+
+impl ToPeano for ConstNat<0> {
+    type Output = Zero;
+}
+impl FromPeano for Zero {
+    type Output = ConstNat<0>;
+}
+// Each value is one more than the last, so each step only has to wrap the previous type.
+macro_rules! const_nats {
+    ($prev:ty; $n:literal $(, $rest:literal)*) => {
+        impl ToPeano for ConstNat<$n> {
+            type Output = Successor<$prev>;
+        }
+        impl FromPeano for Successor<$prev> {
+            type Output = ConstNat<$n>;
+        }
+        const_nats!(Successor<$prev>; $($rest),*);
+    };
+    ($prev:ty;) => {};
+}
+const_nats!(Zero;
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+    33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48,
+    49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
+);
+
+proofs! {
+    Equal<Five, <ConstNat<5> as ToPeano>::Output>;
+    Equal<ConstNat<5>, <Five as FromPeano>::Output>;
+    Equal<Zero, <ConstNat<0> as ToPeano>::Output>;
+    Equal<ConstNat<9>, <<Three as Square>::Output as FromPeano>::Output>;
+    // All the way there and back.
+    Equal<ConstNat<7>, <<ConstNat<7> as ToPeano>::Output as FromPeano>::Output>;
+    Equal<Eight, <<Eight as FromPeano>::Output as ToPeano>::Output>;
+}
+// Past the limit, there's no impl:
+// proof! { <ConstNat<65> as ToPeano>::Output }
+
+const _: () = assert!(<ConstNat<5> as Reify>::OUTPUT == <Five as Reify>::OUTPUT);
+const _: () = assert!(<<ConstNat<5> as ToPeano>::Output as Reify>::OUTPUT == 5);
+const _: () = assert!(<<Five as FromPeano>::Output as Reify>::OUTPUT == 5);
+const _: () = assert!(<<ConstNat<CONST_LIMIT> as ToPeano>::Output as Reify>::OUTPUT == CONST_LIMIT as u64);
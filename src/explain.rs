@@ -0,0 +1,239 @@
+//! Rendering proofs as readable expressions, for teaching and for test output.
+//!
+//! `<Two as Sum<Two>>::Output` is just `Four` by the time anyone can look at it, so the
+//! expressions here are spelled with their own types, which remember how they were built.
+
+use ::core::fmt;
+use ::core::marker::PhantomData;
+use ::alloc::format;
+use ::alloc::string::{String, ToString};
+use ::alloc::vec;
+use ::alloc::vec::Vec;
+
+use crate::nat::*;
+use crate::proof::{Congruent, Equal};
+
+/// Something that can be written out as text.
+pub trait ProofExpr {
+    fn render() -> String;
+}
+/// An expression with a natural for its value.
+pub trait NatExpr: ProofExpr {
+    type Value: Nat;
+}
+
+impl ProofExpr for Zero {
+    fn render() -> String { "0".to_string() }
+}
+impl NatExpr for Zero {
+    type Value = Zero;
+}
+impl<N: Nat + Reify> ProofExpr for Successor<N> {
+    fn render() -> String { Self::OUTPUT.to_string() }
+}
+impl<N: Nat + Reify> NatExpr for Successor<N> {
+    type Value = Successor<N>;
+}
+
+/// `a + b`, as an expression.
+pub struct Plus<A, B> { _a: PhantomData<A>, _b: PhantomData<B> }
+impl<A: ProofExpr, B: ProofExpr> ProofExpr for Plus<A, B> {
+    fn render() -> String { format!("({} + {})", A::render(), B::render()) }
+}
+impl<A: NatExpr, B: NatExpr> NatExpr for Plus<A, B> where
+    A::Value: Sum<B::Value>,
+    <A::Value as Sum<B::Value>>::Output: Nat,
+{
+    type Value = <A::Value as Sum<B::Value>>::Output;
+}
+
+/// `a - b`, as an expression. Like `Difference`, there's no value below zero.
+pub struct Minus<A, B> { _a: PhantomData<A>, _b: PhantomData<B> }
+impl<A: ProofExpr, B: ProofExpr> ProofExpr for Minus<A, B> {
+    fn render() -> String { format!("({} - {})", A::render(), B::render()) }
+}
+impl<A: NatExpr, B: NatExpr> NatExpr for Minus<A, B> where
+    A::Value: Difference<B::Value>,
+    <A::Value as Difference<B::Value>>::Output: Nat,
+{
+    type Value = <A::Value as Difference<B::Value>>::Output;
+}
+
+/// `a * b`, as an expression.
+pub struct Times<A, B> { _a: PhantomData<A>, _b: PhantomData<B> }
+impl<A: ProofExpr, B: ProofExpr> ProofExpr for Times<A, B> {
+    fn render() -> String { format!("({} * {})", A::render(), B::render()) }
+}
+impl<A: NatExpr, B: NatExpr> NatExpr for Times<A, B> where
+    A::Value: Product<B::Value>,
+    <A::Value as Product<B::Value>>::Output: Nat,
+{
+    type Value = <A::Value as Product<B::Value>>::Output;
+}
+
+// Both sides of an `Equal` are the same type, so they render the same.
+impl<A: ProofExpr, B: ProofExpr + Congruent<A>> ProofExpr for Equal<A, B> {
+    fn render() -> String { format!("{} = {}", A::render(), B::render()) }
+}
+/// Being able to write this type is proof that the two expressions have the same value.
+/// Unlike `Equal`, the two sides can be written differently.
+pub struct ExprEqual<L: NatExpr, R: NatExpr> where R::Value: Congruent<L::Value> {
+    _l: PhantomData<L>,
+    _r: PhantomData<R>,
+}
+impl<L: NatExpr, R: NatExpr> ProofExpr for ExprEqual<L, R> where R::Value: Congruent<L::Value> {
+    fn render() -> String { format!("{} = {}", L::render(), R::render()) }
+}
+
+/// Write out a proof, or any other expression.
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// assert_eq!(explain::<ExprEqual<Plus<Two, Two>, Four>>(), "(2 + 2) = 4");
+/// assert_eq!(explain::<ExprEqual<Times<Two, Three>, Plus<Five, One>>>(), "(2 * 3) = (5 + 1)");
+/// assert_eq!(explain::<ExprEqual<Minus<Nine, Times<Two, Two>>, Five>>(), "(9 - (2 * 2)) = 5");
+/// assert_eq!(explain::<Equal<Four, <Two as Sum<Two>>::Output>>(), "4 = 4");
+/// assert_eq!(explain::<Zero>(), "0");
+/// ```
+///
+/// A false statement can't be named, let alone explained:
+///
+/// ```compile_fail
+/// use sqrt_irrational::prelude::*;
+/// explain::<ExprEqual<Plus<Two, Two>, Five>>();
+/// ```
+pub fn explain<T: ProofExpr>() -> String {
+    T::render()
+}
+
+/// What a `ProofTree` node does with its children.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    Sum,
+    Difference,
+    Product,
+    /// Both children have the same value, which is the node's value too.
+    Equal,
+}
+
+/// An expression taken apart, with every step's value reified, so it can be walked or printed.
+///
+/// A natural is a leaf, with no operation and no children. Everything else is an operation
+/// applied to its children, in order. The values come from the types, so a tree built by
+/// `BuildProofTree` is right by construction, and `check` redoes the arithmetic at runtime
+/// for trees that weren't.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofTree {
+    pub op: Option<Operation>,
+    pub children: Vec<ProofTree>,
+    pub value: u64,
+}
+impl ProofTree {
+    pub fn leaf(value: u64) -> Self {
+        ProofTree { op: None, children: Vec::new(), value }
+    }
+    pub fn node(op: Operation, children: Vec<ProofTree>, value: u64) -> Self {
+        ProofTree { op: Some(op), children, value }
+    }
+    /// Whether every node's value is what its operation makes of its children's.
+    pub fn check(&self) -> bool {
+        let holds = match (self.op, &self.children[..]) {
+            (None, []) => true,
+            (Some(Operation::Sum), [a, b]) => a.value.checked_add(b.value) == Some(self.value),
+            (Some(Operation::Difference), [a, b]) => a.value.checked_sub(b.value) == Some(self.value),
+            (Some(Operation::Product), [a, b]) => a.value.checked_mul(b.value) == Some(self.value),
+            (Some(Operation::Equal), [a, b]) => a.value == self.value && b.value == self.value,
+            _ => false,
+        };
+        holds && self.children.iter().all(ProofTree::check)
+    }
+}
+// `Sum(2, 3) -> 5`, with children written the same way.
+impl fmt::Display for ProofTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = match self.op {
+            None => return write!(f, "{}", self.value),
+            Some(op) => op,
+        };
+        write!(f, "{:?}(", op)?;
+        for (i, child) in self.children.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", child)?;
+        }
+        write!(f, ") -> {}", self.value)
+    }
+}
+
+/// An expression that can be taken apart into a `ProofTree`.
+pub trait BuildProofTree {
+    fn build() -> ProofTree;
+}
+impl BuildProofTree for Zero {
+    fn build() -> ProofTree { ProofTree::leaf(0) }
+}
+impl<N: Nat + Reify> BuildProofTree for Successor<N> {
+    fn build() -> ProofTree { ProofTree::leaf(Self::OUTPUT) }
+}
+impl<A: NatExpr + BuildProofTree, B: NatExpr + BuildProofTree> BuildProofTree for Plus<A, B> where
+    Self: NatExpr,
+    <Self as NatExpr>::Value: Reify,
+{
+    fn build() -> ProofTree {
+        ProofTree::node(Operation::Sum, vec![A::build(), B::build()], <Self as NatExpr>::Value::OUTPUT)
+    }
+}
+impl<A: NatExpr + BuildProofTree, B: NatExpr + BuildProofTree> BuildProofTree for Minus<A, B> where
+    Self: NatExpr,
+    <Self as NatExpr>::Value: Reify,
+{
+    fn build() -> ProofTree {
+        ProofTree::node(Operation::Difference, vec![A::build(), B::build()], <Self as NatExpr>::Value::OUTPUT)
+    }
+}
+impl<A: NatExpr + BuildProofTree, B: NatExpr + BuildProofTree> BuildProofTree for Times<A, B> where
+    Self: NatExpr,
+    <Self as NatExpr>::Value: Reify,
+{
+    fn build() -> ProofTree {
+        ProofTree::node(Operation::Product, vec![A::build(), B::build()], <Self as NatExpr>::Value::OUTPUT)
+    }
+}
+impl<L: NatExpr + BuildProofTree, R: NatExpr + BuildProofTree> BuildProofTree for ExprEqual<L, R> where
+    R::Value: Congruent<L::Value>,
+    L::Value: Reify,
+{
+    fn build() -> ProofTree {
+        ProofTree::node(Operation::Equal, vec![L::build(), R::build()], L::Value::OUTPUT)
+    }
+}
+
+/// Take a proof, or any other expression, apart into a tree.
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// let tree = proof_tree::<Plus<Two, Three>>();
+/// assert_eq!(tree, ProofTree::node(Operation::Sum, vec![ProofTree::leaf(2), ProofTree::leaf(3)], 5));
+/// assert_eq!(tree.to_string(), "Sum(2, 3) -> 5");
+///
+/// let tree = proof_tree::<ExprEqual<Minus<Nine, Times<Two, Two>>, Five>>();
+/// assert_eq!(tree.to_string(), "Equal(Difference(9, Product(2, 2) -> 4) -> 5, 5) -> 5");
+/// assert_eq!(tree.op, Some(Operation::Equal));
+/// let product = &tree.children[0].children[1];
+/// assert_eq!((product.op, product.value, product.children.len()), (Some(Operation::Product), 4, 2));
+/// assert!(tree.check());
+///
+/// // A tree put together by hand can be wrong, and `check` finds it.
+/// let wrong = ProofTree::node(Operation::Sum, vec![ProofTree::leaf(2), ProofTree::leaf(2)], 5);
+/// assert!(!wrong.check());
+/// ```
+pub fn proof_tree<T: BuildProofTree>() -> ProofTree {
+    T::build()
+}
+
+proofs! {
+    ExprEqual<Plus<Two, Three>, Five>;
+    ExprEqual<Times<Three, Three>, Plus<Four, Five>>;
+    ExprEqual<Minus<Four, Four>, Zero>;
+}
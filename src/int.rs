@@ -0,0 +1,224 @@
+//! Signed integers, as a sign and a natural.
+//!
+//! `Pos<N>` is `n` and `Neg<N>` is `-n`. The arithmetic below never gives `Neg<Zero>`,
+//! so results can be compared with `Equal`.
+
+use ::core::marker::PhantomData;
+
+use crate::nat::*;
+use crate::proof::Equal;
+
+pub trait Int {}
+/// `n`.
+#[derive(Default)]
+pub struct Pos<N: Nat> { _n: PhantomData<N> }
+impl<N: Nat> Int for Pos<N> {}
+/// `-n`. Write zero as `Pos<Zero>`, not `Neg<Zero>`.
+#[derive(Default)]
+pub struct Neg<N: Nat> { _n: PhantomData<N> }
+impl<N: Nat> Int for Neg<N> {}
+
+pub trait ReifyInt {
+    const OUTPUT: i64;
+}
+impl<N: Nat + Reify> ReifyInt for Pos<N> {
+    const OUTPUT: i64 = N::OUTPUT as i64;
+}
+impl<N: Nat + Reify> ReifyInt for Neg<N> {
+    const OUTPUT: i64 = -(N::OUTPUT as i64);
+}
+
+/// Absolute value.
+pub trait IntAbs {
+    type Output: Nat;
+}
+impl<N: Nat> IntAbs for Pos<N> {
+    type Output = N;
+}
+impl<N: Nat> IntAbs for Neg<N> {
+    type Output = N;
+}
+
+/// Negation.
+pub trait IntNegate {
+    type Output: Int;
+}
+impl IntNegate for Pos<Zero> {
+    type Output = Pos<Zero>;
+}
+impl<N: Nat> IntNegate for Pos<Successor<N>> {
+    type Output = Neg<Successor<N>>;
+}
+impl<N: Nat> IntNegate for Neg<N> {
+    type Output = Pos<N>;
+}
+
+/// `a - b` for naturals, which goes negative when `a < b`.
+pub trait SignedDifference<B> {
+    type Output: Int;
+}
+impl<A: Nat, B: Nat> SignedDifference<B> for A where
+    A: Compare<B>,
+    A: SignedDifferenceStep<B, <A as Compare<B>>::Output>,
+{
+    type Output = <A as SignedDifferenceStep<B, <A as Compare<B>>::Output>>::Output;
+}
+pub trait SignedDifferenceStep<B, Ordering> {
+    type Output: Int;
+}
+impl<A: Nat, B: Nat> SignedDifferenceStep<B, Less> for A where
+    B: Difference<A>,
+    <B as Difference<A>>::Output: Nat,
+{
+    type Output = Neg<<B as Difference<A>>::Output>;
+}
+impl<A: Nat, B: Nat> SignedDifferenceStep<B, EqualOrd> for A {
+    type Output = Pos<Zero>;
+}
+impl<A: Nat, B: Nat> SignedDifferenceStep<B, Greater> for A where
+    A: Difference<B>,
+    <A as Difference<B>>::Output: Nat,
+{
+    type Output = Pos<<A as Difference<B>>::Output>;
+}
+
+/// Integer addition.
+pub trait IntSum<R> {
+    type Output: Int;
+}
+impl<A: Nat, B: Nat> IntSum<Pos<B>> for Pos<A> where A: Sum<B>, <A as Sum<B>>::Output: Nat {
+    type Output = Pos<<A as Sum<B>>::Output>;
+}
+impl<A: Nat, B: Nat> IntSum<Neg<B>> for Neg<A> where A: Sum<B>, <A as Sum<B>>::Output: Nat {
+    type Output = Neg<<A as Sum<B>>::Output>;
+}
+impl<A: Nat, B: Nat> IntSum<Neg<B>> for Pos<A> where A: SignedDifference<B> {
+    type Output = <A as SignedDifference<B>>::Output;
+}
+impl<A: Nat, B: Nat> IntSum<Pos<B>> for Neg<A> where B: SignedDifference<A> {
+    type Output = <B as SignedDifference<A>>::Output;
+}
+
+/// Integer subtraction.
+pub trait IntDifference<R> {
+    type Output: Int;
+}
+impl<A, B> IntDifference<B> for A where B: IntNegate, A: IntSum<<B as IntNegate>::Output> {
+    type Output = <A as IntSum<<B as IntNegate>::Output>>::Output;
+}
+
+/// Integer multiplication.
+pub trait IntProduct<R> {
+    type Output: Int;
+}
+impl<A: Nat, B: Nat> IntProduct<Pos<B>> for Pos<A> where A: Product<B>, <A as Product<B>>::Output: Nat {
+    type Output = Pos<<A as Product<B>>::Output>;
+}
+impl<A: Nat, B: Nat> IntProduct<Neg<B>> for Neg<A> where A: Product<B>, <A as Product<B>>::Output: Nat {
+    type Output = Pos<<A as Product<B>>::Output>;
+}
+impl<A: Nat, B: Nat> IntProduct<Neg<B>> for Pos<A> where
+    A: Product<B>,
+    <A as Product<B>>::Output: Nat,
+    Pos<<A as Product<B>>::Output>: IntNegate,
+{
+    type Output = <Pos<<A as Product<B>>::Output> as IntNegate>::Output;
+}
+impl<A: Nat, B: Nat> IntProduct<Pos<B>> for Neg<A> where
+    A: Product<B>,
+    <A as Product<B>>::Output: Nat,
+    Pos<<A as Product<B>>::Output>: IntNegate,
+{
+    type Output = <Pos<<A as Product<B>>::Output> as IntNegate>::Output;
+}
+
+/// The remainder of an integer mod `M`, in `0..M` whatever the sign, so that `a ≡ b (mod m)`
+/// is `IntRemainder` giving the same natural for both. `-n` goes to `m - (n mod m)`, and then
+/// round again in case that's `m`.
+pub trait IntRemainder<M: Nat> {
+    type Output: Nat;
+}
+impl<N: Nat, M: Nat> IntRemainder<Successor<M>> for Pos<N> where N: Remainder<Successor<M>> {
+    type Output = <N as Remainder<Successor<M>>>::Output;
+}
+impl<N: Nat, M: Nat> IntRemainder<Successor<M>> for Neg<N> where
+    N: Remainder<Successor<M>>,
+    Successor<M>: Difference<<N as Remainder<Successor<M>>>::Output>,
+    <Successor<M> as Difference<<N as Remainder<Successor<M>>>::Output>>::Output: Nat + Remainder<Successor<M>>,
+{
+    type Output = <<Successor<M> as Difference<<N as Remainder<Successor<M>>>::Output>>::Output as Remainder<Successor<M>>>::Output;
+}
+
+/// `ax + by`, for naturals `a` and `b` and integers `x` and `y`.
+pub trait BezoutSum<B, X, Y> {
+    type Output: Int;
+}
+impl<A: Nat, B: Nat, X, Y> BezoutSum<B, X, Y> for A where
+    Pos<A>: IntProduct<X>,
+    Pos<B>: IntProduct<Y>,
+    <Pos<A> as IntProduct<X>>::Output: IntSum<<Pos<B> as IntProduct<Y>>::Output>,
+{
+    type Output = <<Pos<A> as IntProduct<X>>::Output as IntSum<<Pos<B> as IntProduct<Y>>::Output>>::Output;
+}
+/// `ax + by`, for naturals `a` and `b` and integers `x` and `y`.
+pub type Bezout<A, B, X, Y> = <A as BezoutSum<B, X, Y>>::Output;
+
+/// The extended Euclidean algorithm: `gcd(a, b)`, along with `x` and `y` such that
+/// `ax + by = gcd(a, b)`.
+///
+/// - `gcd(a, 0) = a = a·1 + 0·0`.
+/// - If `a = qb + r` and `gcd(b, r) = bx' + ry'`, then
+///   `gcd(a, b) = bx' + (a - qb)y' = ay' + b(x' - qy')`.
+pub trait ExtGcd<B> {
+    type Gcd: Nat;
+    type X: Int;
+    type Y: Int;
+}
+impl<A: Nat> ExtGcd<Zero> for A {
+    type Gcd = A;
+    type X = Pos<One>;
+    type Y = Pos<Zero>;
+}
+impl<A: Nat, B: Nat> ExtGcd<Successor<B>> for A where
+    A: Quotient<Successor<B>> + Remainder<Successor<B>>,
+    Successor<B>: ExtGcd<<A as Remainder<Successor<B>>>::Output>,
+    Pos<<A as Quotient<Successor<B>>>::Output>:
+        IntProduct<<Successor<B> as ExtGcd<<A as Remainder<Successor<B>>>::Output>>::Y>,
+    <Successor<B> as ExtGcd<<A as Remainder<Successor<B>>>::Output>>::X: IntDifference<
+        <Pos<<A as Quotient<Successor<B>>>::Output> as IntProduct<
+            <Successor<B> as ExtGcd<<A as Remainder<Successor<B>>>::Output>>::Y,
+        >>::Output,
+    >,
+{
+    type Gcd = <Successor<B> as ExtGcd<<A as Remainder<Successor<B>>>::Output>>::Gcd;
+    type X = <Successor<B> as ExtGcd<<A as Remainder<Successor<B>>>::Output>>::Y;
+    type Y = <<Successor<B> as ExtGcd<<A as Remainder<Successor<B>>>::Output>>::X as IntDifference<
+        <Pos<<A as Quotient<Successor<B>>>::Output> as IntProduct<
+            <Successor<B> as ExtGcd<<A as Remainder<Successor<B>>>::Output>>::Y,
+        >>::Output,
+    >>::Output;
+}
+
+proofs! {
+    // Integer arithmetic tests:
+    Equal<Pos<Five>, <Pos<Two> as IntSum<Pos<Three>>>::Output>;
+    Equal<Neg<One>, <Pos<Two> as IntSum<Neg<Three>>>::Output>;
+    Equal<Pos<Zero>, <Neg<Three> as IntSum<Pos<Three>>>::Output>;
+    Equal<Neg<Five>, <Neg<Two> as IntDifference<Pos<Three>>>::Output>;
+    Equal<Neg<Six>, <Pos<Two> as IntProduct<Neg<Three>>>::Output>;
+    Equal<Pos<Zero>, <Pos<Zero> as IntProduct<Neg<Three>>>::Output>;
+    Equal<Pos<Six>, <Neg<Two> as IntProduct<Neg<Three>>>::Output>;
+    Equal<Pos<Four>, <Neg<Four> as IntNegate>::Output>;
+    Equal<Two, <Pos<Seven> as IntRemainder<Five>>::Output>;
+    Equal<Three, <Neg<Seven> as IntRemainder<Five>>::Output>;
+    Equal<Zero, <Neg<Six> as IntRemainder<Three>>::Output>;
+    Equal<Zero, <Pos<Zero> as IntRemainder<Four>>::Output>;
+
+    // Extended gcd tests:
+    Equal<One, <Five as ExtGcd<Three>>::Gcd>;
+    Equal<Pos<One>, Bezout<Five, Three, <Five as ExtGcd<Three>>::X, <Five as ExtGcd<Three>>::Y>>;
+    Equal<Two, <Four as ExtGcd<Six>>::Gcd>;
+    Equal<Pos<Two>, Bezout<Four, Six, <Four as ExtGcd<Six>>::X, <Four as ExtGcd<Six>>::Y>>;
+}
+const _: () = assert!(<<Five as ExtGcd<Three>>::X as ReifyInt>::OUTPUT == -1);
+const _: () = assert!(<<Five as ExtGcd<Three>>::Y as ReifyInt>::OUTPUT == 2);
@@ -0,0 +1,370 @@
+// Inferno's Dare:
+// https://discord.com/channels/273534239310479360/832043898456113182/867946092686372894
+// > I dare you to prove sqrt(2) is not in Q with Rust.
+
+//! # Proving `sqrt(2)` is not inside the set of rational numbers with Rust.
+//!
+//! Rust's type system can be used as a verifier for constructive logic.
+
+// Nothing here is ever used at runtime. That's kind of the point.
+#![allow(dead_code)]
+// `u64::is_multiple_of` is only stable from Rust 1.87, much newer than the rest of this needs.
+#![allow(clippy::manual_is_multiple_of)]
+// The proofs only need `core`. Reifying lists into `Vec`s needs the `alloc` feature.
+#![no_std]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+/// Convenience macro for proof verification.
+#[macro_export]
+macro_rules! proof {
+    ($vis:vis type $name:ident = $t:ty) => {
+        #[allow(dead_code)]
+        $vis type $name = $t;
+        $crate::proof!($name);
+    };
+    ($t:ty) => {
+        // To verify a proof, we just need to force rustc to check that it's a valid type.
+        const _: Option<$t> = None;
+    }
+}
+/// An even more convenient macro for proof verification.
+#[macro_export]
+macro_rules! proofs {
+    ($vis:vis type $name:ident = $t:ty
+     $(; $($rest:tt)*)?
+    ) => {
+        $crate::proof!($vis type $name = $t);
+        $(
+            $crate::proofs!($($rest)*);
+        )?
+    };
+    ($t:ty
+     $(; $($rest:tt)*)?
+    ) => {
+        $crate::proof!($t);
+        $(
+            $crate::proofs!($($rest)*);
+        )?
+    };
+    ($($t:ty);* $(;)?) => {
+        $(
+            $crate::proof!($t);
+        )*
+    }
+}
+
+/// `match_ord!(<A as Compare<B>>::Output => { Less => X, EqualOrd => Y, Greater => Z })`
+/// is whichever of `X`, `Y` and `Z` the comparison picks.
+///
+/// All three arms have to be valid types, the same as with `If`.
+#[macro_export]
+macro_rules! match_ord {
+    ($ordering:ty => {
+        Less => $less:ty,
+        EqualOrd => $equal:ty,
+        Greater => $greater:ty $(,)?
+    }) => {
+        <$ordering as $crate::nat::SelectOrd<$less, $equal, $greater>>::Output
+    };
+}
+/// `match_bool!(C => { True => X, False => Y })` is `X` if `C` is `True`, and `Y` otherwise.
+#[macro_export]
+macro_rules! match_bool {
+    ($condition:ty => {
+        True => $then:ty,
+        False => $else:ty $(,)?
+    }) => {
+        <$condition as $crate::nat::If<$then, $else>>::Output
+    };
+}
+
+/// `nat_from_runtime!(n, max = 8, |N| body)` matches the runtime `n` against `0..=8`, and in
+/// each arm evaluates `body` with `N` standing for that natural's type. It gives
+/// `Ok(body)`, or an `OutOfRange` error if `n` is bigger than `max`, which can be up to `9`.
+///
+/// Unlike `with_nat`, every arm is its own copy of `body` with a concrete type, so it
+/// can use whatever that type implements, without a continuation spelling out bounds for all of them:
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// use sqrt_irrational::nat::{IsPrime, OutOfRange};
+///
+/// fn is_prime(n: u64) -> Result<bool, OutOfRange> {
+///     nat_from_runtime!(n, max = 8, |N| <<N as IsPrime>::Output as Bool>::OUTPUT)
+/// }
+/// assert_eq!(is_prime(7), Ok(true));
+/// assert_eq!(is_prime(8), Ok(false));
+/// assert_eq!(is_prime(9), Err(OutOfRange { value: 9, max: 8 }));
+///
+/// let squares: Vec<_> = (0..5).map(|n| nat_from_runtime!(n, max = 3, |N| <<N as Square>::Output as Reify>::OUTPUT)).collect();
+/// assert_eq!(squares, [Ok(0), Ok(1), Ok(4), Ok(9), Err(OutOfRange { value: 4, max: 3 })]);
+///
+/// // That works in a const context too.
+/// const SIX: Result<u64, OutOfRange> = nat_from_runtime!(3, max = 9, |N| <<N as Sum<N>>::Output as Reify>::OUTPUT);
+/// assert_eq!(SIX, Ok(6));
+/// ```
+#[macro_export]
+macro_rules! nat_from_runtime {
+    ($n:expr, max = 0, |$t:ident| $body:expr $(,)?) => {
+        $crate::nat_from_runtime!(@match $n, 0, $t, $body; 0 => Zero)
+    };
+    ($n:expr, max = 1, |$t:ident| $body:expr $(,)?) => {
+        $crate::nat_from_runtime!(@match $n, 1, $t, $body; 0 => Zero, 1 => One)
+    };
+    ($n:expr, max = 2, |$t:ident| $body:expr $(,)?) => {
+        $crate::nat_from_runtime!(@match $n, 2, $t, $body; 0 => Zero, 1 => One, 2 => Two)
+    };
+    ($n:expr, max = 3, |$t:ident| $body:expr $(,)?) => {
+        $crate::nat_from_runtime!(@match $n, 3, $t, $body; 0 => Zero, 1 => One, 2 => Two, 3 => Three)
+    };
+    ($n:expr, max = 4, |$t:ident| $body:expr $(,)?) => {
+        $crate::nat_from_runtime!(@match $n, 4, $t, $body; 0 => Zero, 1 => One, 2 => Two, 3 => Three, 4 => Four)
+    };
+    ($n:expr, max = 5, |$t:ident| $body:expr $(,)?) => {
+        $crate::nat_from_runtime!(@match $n, 5, $t, $body; 0 => Zero, 1 => One, 2 => Two, 3 => Three, 4 => Four,
+            5 => Five)
+    };
+    ($n:expr, max = 6, |$t:ident| $body:expr $(,)?) => {
+        $crate::nat_from_runtime!(@match $n, 6, $t, $body; 0 => Zero, 1 => One, 2 => Two, 3 => Three, 4 => Four,
+            5 => Five, 6 => Six)
+    };
+    ($n:expr, max = 7, |$t:ident| $body:expr $(,)?) => {
+        $crate::nat_from_runtime!(@match $n, 7, $t, $body; 0 => Zero, 1 => One, 2 => Two, 3 => Three, 4 => Four,
+            5 => Five, 6 => Six, 7 => Seven)
+    };
+    ($n:expr, max = 8, |$t:ident| $body:expr $(,)?) => {
+        $crate::nat_from_runtime!(@match $n, 8, $t, $body; 0 => Zero, 1 => One, 2 => Two, 3 => Three, 4 => Four,
+            5 => Five, 6 => Six, 7 => Seven, 8 => Eight)
+    };
+    ($n:expr, max = 9, |$t:ident| $body:expr $(,)?) => {
+        $crate::nat_from_runtime!(@match $n, 9, $t, $body; 0 => Zero, 1 => One, 2 => Two, 3 => Three, 4 => Four,
+            5 => Five, 6 => Six, 7 => Seven, 8 => Eight, 9 => Nine)
+    };
+    (@match $n:expr, $max:literal, $t:ident, $body:expr; $($value:literal => $nat:ident),*) => {
+        match $n {
+            $($value => ::core::result::Result::Ok({
+                type $t = $crate::nat::$nat;
+                $body
+            }),)*
+            value => ::core::result::Result::Err($crate::nat::OutOfRange { value, max: $max }),
+        }
+    };
+}
+
+/// Check at compile time that two naturals reify to the same number.
+///
+/// Unlike `Equal`, this compares values rather than types, so it works on anything
+/// that implements `Reify`, and failing it says which two types disagreed.
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// static_assert_nat_eq!(<Two as Sum<Three>>::Output, <Three as Sum<Two>>::Output);
+/// ```
+///
+/// ```compile_fail
+/// use sqrt_irrational::prelude::*;
+/// static_assert_nat_eq!(<Two as Sum<Two>>::Output, Five);
+/// ```
+#[macro_export]
+macro_rules! static_assert_nat_eq {
+    ($a:ty, $b:ty $(,)?) => {
+        const _: () = ::core::assert!(
+            <$a as $crate::nat::Reify>::OUTPUT == <$b as $crate::nat::Reify>::OUTPUT,
+            ::core::concat!("`", ::core::stringify!($a), "` and `", ::core::stringify!($b), "` aren't equal"),
+        );
+    };
+}
+
+/// Declare a lemma about every natural and prove it by induction, in one go.
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// type OneProductStatement<N> = Equal<N, <One as Product<N>>::Output>;
+/// induct! {
+///     /// `1·n = n`.
+///     pub trait OneProduct for N over Nat;
+///     base: OneProductStatement<Zero>;
+///     step: OneProductStatement<N> => OneProductStatement<Successor<N>> where {
+///         // Induction hypothesis, which rustc needs spelled out as an equation.
+///         One: Product<N, Output = N>,
+///     };
+/// }
+/// proof! { <Five as OneProduct>::Proof }
+/// ```
+///
+/// This generates the trait, with a `Proof` that's the statement at `Self`, and two impls:
+/// one for `Zero` proving `base`, and one for `Successor<N>` proving the right side of
+/// `step` from the left side at `N`. Each `where` clause lists the facts its case relies on.
+/// rustc checks the impls for all naturals at once, so if the statement doesn't follow
+/// from those, it doesn't compile:
+///
+/// ```compile_fail
+/// use sqrt_irrational::prelude::*;
+/// type OneProductStatement<N> = Equal<N, <One as Product<N>>::Output>;
+/// induct! {
+///     pub trait OneProduct for N over Nat;
+///     base: OneProductStatement<Zero>;
+///     step: OneProductStatement<N> => OneProductStatement<Successor<N>>;
+/// }
+/// ```
+#[macro_export]
+macro_rules! induct {
+    (
+        $(#[$attr:meta])*
+        $vis:vis trait $name:ident for $n:ident over Nat;
+        base: $base:ty $(where { $($base_where:tt)* })?;
+        step: $hypothesis:ty => $step:ty $(where { $($step_where:tt)* })?;
+    ) => {
+        $(#[$attr])*
+        $vis trait $name: $crate::nat::Nat {
+            type Proof;
+        }
+        impl $name for $crate::nat::Zero where $($($base_where)*)? {
+            type Proof = $base;
+        }
+        impl<$n: $crate::nat::Nat> $name for $crate::nat::Successor<$n> where
+            $n: $name<Proof = $hypothesis>,
+            $($($step_where)*)?
+        {
+            type Proof = $step;
+        }
+    };
+}
+
+/// Proofs as named functions, for when `proof!` isn't enough to tell which one broke.
+///
+/// `#[proof]` goes on a function with an empty body, and its signature is the proof:
+/// a return type that has to be well-formed, a `where` clause that has to hold, or both.
+/// It's never called.
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// use sqrt_irrational::attr;
+///
+/// #[attr::proof]
+/// fn two_and_three_are_five() -> Equal<Five, <Two as Sum<Three>>::Output> {}
+///
+/// #[attr::proof]
+/// fn four_is_even() where Four: Even {}
+///
+/// // Generic ones are checked for every choice of parameters at once. Their `where` clauses
+/// // are assumed, like an impl's, and the return type has to follow from them:
+/// #[attr::proof]
+/// fn plus_one<N: Nat>() -> Equal<Successor<N>, <N as Sum<One>>::Output> {}
+/// ```
+///
+/// When one doesn't hold, the error points at that function's signature, rather than at
+/// one of a run of `const _`s:
+///
+/// ```compile_fail,E0277
+/// use sqrt_irrational::prelude::*;
+/// use sqrt_irrational::attr;
+///
+/// #[attr::proof]
+/// fn two_and_two_are_five() -> Equal<Five, <Two as Sum<Two>>::Output> {}
+/// ```
+///
+/// This is `attr::proof` rather than living in the prelude, because the prelude's `proof!`
+/// has the same name.
+pub mod attr {
+    pub use ::sqrt_irrational_macros::proof;
+}
+
+/// For finding out which part of a proof doesn't hold.
+///
+/// `why_not!(T)` checks the same thing as `proof!(T)`, but the pieces of `T` first, one at a
+/// time, from the inside out: each `<A as Trait<B>>::Output` after `A` and `B`, each `Name<A, B>`
+/// after `A` and `B`, and each tuple after what's in it. So when `T` doesn't hold, the first
+/// error is about the smallest part of it that doesn't, rather than about all of `T`.
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// why_not!(Equal<Five, <<Two as Product<Two>>::Output as Sum<One>>::Output>);
+/// ```
+///
+/// In this one, `2 + 3` and `(2 + 3)·2` are fine, and then `10 - 12` underflows. The first
+/// error points at the `Difference`, and not the `Equal` around it:
+///
+/// ```compile_fail,E0277
+/// use sqrt_irrational::prelude::*;
+/// type Twelve = <Three as Product<Four>>::Output;
+/// why_not!(Equal<Zero, <<<Two as Sum<Three>>::Output as Product<Two>>::Output as Difference<Twelve>>::Output>);
+/// ```
+///
+/// Type aliases are a single piece as far as `why_not!` can see, so it can only look inside
+/// what's written out in the macro call.
+pub use ::sqrt_irrational_macros::why_not;
+
+pub mod nat;
+pub mod list;
+pub mod ratio;
+pub mod binary;
+pub mod constant;
+pub mod int;
+pub mod proof;
+#[cfg(feature = "alloc")]
+pub mod explain;
+
+pub use crate::binary::*;
+pub use crate::constant::*;
+#[cfg(feature = "alloc")]
+pub use crate::explain::*;
+pub use crate::int::*;
+pub use crate::list::*;
+pub use crate::nat::*;
+pub use crate::proof::*;
+pub use crate::ratio::*;
+
+/// The traits and types most proofs need, for `use sqrt_irrational::prelude::*;`.
+pub mod prelude {
+    pub use crate::{induct, match_bool, match_ord, nat_from_runtime, proof, proofs, static_assert_nat_eq, why_not};
+    pub use crate::nat::{
+        Nat, Zero, Successor, One, Two, Three, Four, Five, Six, Seven, Eight, Nine,
+        Reify, ReifyAs, TryReify, ReifyError, ReifyDepth, NatValue,
+        Sum, Difference, Product, Power, Square, Quotient, Remainder, Gcd,
+        LessThan, LessThanOrEqual, GreaterThan, GreaterThanOrEqual, Divides, Coprime, Prime,
+        CanAdd, CanSubtract, NonZero, CoprimeWith,
+        Parity, IsEven, Even, Odd, Bool, True, False, Not, And, Or, If, TypeEq,
+        Compare, Comparison, Less, EqualOrd, Greater, Min, Max, Clamp,
+        NatFn, NatBinFn, SumFn, AddFn, DifferenceFn, ProductFn, MulFn, QuotientFn, GcdFn, MinFn, MaxFn,
+    };
+    #[cfg(feature = "alloc")]
+    pub use crate::nat::to_string_radix;
+    pub use crate::list::{TNil, TCons, ReifyList, ListLength, Fold, SumList, SumListAcc, ProductList, RangeList, NatRange, MapList, PascalRow,
+        Enumerate, Naturals};
+    pub use crate::ratio::{Ratio, ValidRatio, ReifyRatio, RatioCompare, RatioEq, RatioAdd, RatioMul, RatioDiv, RatioSimplify,
+        SignedRatio, ReifySignedRatio, RatioAbs, RatioCompareMagnitude, Reciprocal, ContinuedFraction, FromContinuedFraction,
+        Convergents, BestApproximation};
+    pub use crate::binary::{BinNat, BZero, PushZero, PushOne, ToPeano, ToBinary};
+    pub use crate::constant::{ConstNat, FromPeano};
+    pub use crate::int::{Int, Pos, Neg, ReifyInt, IntAbs, IntNegate, IntSum, IntDifference, IntProduct, IntRemainder, ExtGcd};
+    pub use crate::proof::{Congruent, Equal};
+    #[cfg(feature = "alloc")]
+    pub use crate::explain::{ProofExpr, NatExpr, Plus, Minus, Times, ExprEqual, explain,
+        ProofTree, Operation, BuildProofTree, proof_tree};
+}
+
+// Every type here is a zero-sized marker, and should stay that way,
+// so that using one as a value never costs anything, in a const context or otherwise.
+macro_rules! assert_zero_sized {
+    ($($t:ty),* $(,)?) => {
+        $(const _: () = ::core::assert!(::core::mem::size_of::<$t>() == 0, ::core::stringify!($t));)*
+    };
+}
+assert_zero_sized! {
+    Zero, One, Nine, Successor<Successor<Zero>>,
+    True, False, Less, EqualOrd, Greater,
+    SquareFn, ApplyFn<SquareFn>, SumFn, NatValue<Five>,
+    TNil, TCons<One, TCons<Two, TNil>>, TLeft<One>, TRight<Two>, OddFn,
+    Ratio<One, Two>, Ratio<Zero, Nine>, SignedRatio<Neg<Three>, Four>,
+    BZero, PushZero<PushOne<BZero>>, PushOne<BZero>, ConstNat<5>,
+    Pos<Two>, Neg<Zero>,
+    Equal<Two, Two>, EvenWitness<Four>, OddWitness<Three>,
+}
+#[cfg(feature = "alloc")]
+assert_zero_sized! {
+    Plus<One, Two>, Minus<Two, One>, Times<Two, Two>, ExprEqual<Plus<Two, Two>, Four>,
+}
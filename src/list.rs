@@ -0,0 +1,373 @@
+//! Type-level lists, and a type-level `Either`.
+
+use ::core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use ::alloc::vec::Vec;
+#[cfg(feature = "json")]
+use ::alloc::string::String;
+
+use crate::nat::*;
+use crate::proof::Equal;
+
+// Type-level lists.
+pub struct TNil;
+pub struct TCons<Head, Tail> { _h: PhantomData<Head>, _t: PhantomData<Tail> }
+
+/// Turn a list of naturals into their values.
+///
+/// `iter` walks the list lazily and works without `alloc`:
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// type L = TCons<Two, TCons<Three, TCons<Five, TNil>>>;
+/// assert_eq!(L::iter().collect::<Vec<_>>(), L::to_vec());
+/// assert_eq!(L::iter().max(), Some(5));
+/// assert_eq!(TNil::iter().count(), 0);
+/// ```
+pub trait ReifyList {
+    fn iter() -> impl Iterator<Item = u64>;
+    #[cfg(feature = "alloc")]
+    fn to_vec() -> Vec<u64> { Self::iter().collect() }
+    /// The values as a JSON array, for handing results on to anything that reads JSON.
+    ///
+    /// ```
+    /// use sqrt_irrational::prelude::*;
+    /// type L = PascalRow<Four>;
+    /// assert_eq!(L::to_vec(), vec![1, 4, 6, 4, 1]);
+    /// assert_eq!(L::to_json(), "[1,4,6,4,1]");
+    /// assert_eq!(TNil::to_json(), "[]");
+    /// ```
+    #[cfg(feature = "json")]
+    fn to_json() -> String {
+        let mut json = String::from("[");
+        for (i, n) in Self::iter().enumerate() {
+            if i > 0 { json.push(','); }
+            json.push_str(&::alloc::format!("{}", n));
+        }
+        json.push(']');
+        json
+    }
+}
+impl ReifyList for TNil {
+    fn iter() -> impl Iterator<Item = u64> { ::core::iter::empty() }
+}
+impl<H: Reify, T: ReifyList> ReifyList for TCons<H, T> {
+    fn iter() -> impl Iterator<Item = u64> { ::core::iter::once(H::OUTPUT).chain(T::iter()) }
+}
+
+/// How many elements a list has.
+pub trait ListLength {
+    type Output: Nat;
+}
+impl ListLength for TNil {
+    type Output = Zero;
+}
+impl<H, T: ListLength> ListLength for TCons<H, T> {
+    type Output = Successor<<T as ListLength>::Output>;
+}
+
+/// Fold a list with a two-argument function `F`, starting from `Init`.
+///
+/// The tail gets folded first, and then the head goes in, so `[a, b]` folds to `F(F(Init, b), a)`.
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// type L = TCons<Three, TCons<One, TCons<Four, TCons<One, TCons<Five, TNil>>>>>;
+/// assert_eq!(<<L as Fold<Zero, SumFn>>::Output as Reify>::OUTPUT, L::iter().sum::<u64>());
+/// assert_eq!(<<L as Fold<One, ProductFn>>::Output as Reify>::OUTPUT, L::iter().product::<u64>());
+/// assert_eq!(<<L as Fold<Zero, MaxFn>>::Output as Reify>::OUTPUT, L::iter().max().unwrap());
+/// assert_eq!(<<L as Fold<Zero, GcdFn>>::Output as Reify>::OUTPUT, 1);
+/// ```
+pub trait Fold<Init, F> {
+    type Output;
+}
+impl<Init, F> Fold<Init, F> for TNil {
+    type Output = Init;
+}
+impl<Init, F, H, T: Fold<Init, F>> Fold<Init, F> for TCons<H, T> where F: NatBinFn<<T as Fold<Init, F>>::Output, H> {
+    type Output = <F as NatBinFn<<T as Fold<Init, F>>::Output, H>>::Output;
+}
+
+/// Add up a list of naturals, which is `Fold<Zero, AddFn>`.
+pub trait SumList {
+    type Output;
+}
+impl<L: Fold<Zero, AddFn>> SumList for L {
+    type Output = <L as Fold<Zero, AddFn>>::Output;
+}
+
+/// Add up a list of naturals, onto `Acc`.
+///
+/// `SumList` has to add up the tail before it can add the head, so it nests one step per
+/// element, and runs out of recursion somewhere past 120 elements. This adds from the left
+/// instead, two elements at a time, carrying the total so far in `Acc`, so it nests half as deep.
+///
+/// The total is a Peano natural too, as deep as its value, so it still has to stay under
+/// rustc's recursion limit. The difference shows on long lists of small numbers. Prefer
+/// `SumList` otherwise: its output unfolds one element at a time, which is easier to reason
+/// about in a proof.
+pub trait SumListAcc<Acc> {
+    type Output;
+}
+impl<Acc> SumListAcc<Acc> for TNil {
+    type Output = Acc;
+}
+impl<Acc: Nat, H: Nat> SumListAcc<Acc> for TCons<H, TNil> where Acc: Sum<H> {
+    type Output = <Acc as Sum<H>>::Output;
+}
+impl<Acc: Nat, H: Nat, I: Nat, T> SumListAcc<Acc> for TCons<H, TCons<I, T>> where
+    Acc: Sum<H>,
+    <Acc as Sum<H>>::Output: Nat + Sum<I>,
+    T: SumListAcc<<<Acc as Sum<H>>::Output as Sum<I>>::Output>,
+{
+    type Output = <T as SumListAcc<<<Acc as Sum<H>>::Output as Sum<I>>::Output>>::Output;
+}
+
+/// Multiply a list of naturals together, which is `Fold<One, MulFn>`.
+pub trait ProductList {
+    type Output;
+}
+impl<L: Fold<One, MulFn>> ProductList for L {
+    type Output = <L as Fold<One, MulFn>>::Output;
+}
+
+// Type-level folds get expensive fast, and blow the recursion limit on longer lists.
+// These only use the list's structure to pick the numbers, and fold at runtime.
+/// The runtime `SumList` (and `ProductList`, below), which agree with the type-level
+/// folds wherever those get far enough:
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// use sqrt_irrational::{sum_list, product_list};
+/// type Empty = TNil;
+/// type Small = TCons<One, TCons<Two, TCons<Three, TNil>>>;
+/// type WithZero = TCons<Four, TCons<Zero, TCons<Two, TNil>>>;
+/// assert_eq!(sum_list::<Empty>(), <<Empty as SumList>::Output as Reify>::OUTPUT);
+/// assert_eq!(sum_list::<Small>(), <<Small as SumList>::Output as Reify>::OUTPUT);
+/// assert_eq!(sum_list::<WithZero>(), <<WithZero as SumList>::Output as Reify>::OUTPUT);
+/// assert_eq!(product_list::<Empty>(), <<Empty as ProductList>::Output as Reify>::OUTPUT);
+/// assert_eq!(product_list::<Small>(), <<Small as ProductList>::Output as Reify>::OUTPUT);
+/// assert_eq!(product_list::<WithZero>(), <<WithZero as ProductList>::Output as Reify>::OUTPUT);
+/// ```
+pub fn sum_list<L: ReifyList>() -> u64 {
+    L::iter().sum()
+}
+/// The runtime `ProductList`.
+pub fn product_list<L: ReifyList>() -> u64 {
+    L::iter().product()
+}
+
+proofs! {
+    // List fold tests:
+    Equal<Zero, <TNil as SumList>::Output>;
+    Equal<One, <TNil as ProductList>::Output>;
+    Equal<Six, <TCons<One, TCons<Two, TCons<Three, TNil>>> as SumList>::Output>;
+    Equal<Six, <TCons<One, TCons<Two, TCons<Three, TNil>>> as ProductList>::Output>;
+    Equal<Zero, <TCons<Four, TCons<Zero, TNil>> as ProductList>::Output>;
+    // Folding with other functions:
+    Equal<Five, <TCons<Three, TCons<Five, TCons<Two, TNil>>> as Fold<Zero, MaxFn>>::Output>;
+    Equal<Two, <TCons<Three, TCons<Five, TCons<Two, TNil>>> as Fold<Nine, MinFn>>::Output>;
+    Equal<Three, <TCons<Six, TCons<Nine, TNil>> as Fold<Zero, GcdFn>>::Output>;
+    Equal<Seven, <TNil as Fold<Seven, ProductFn>>::Output>;
+    // The tail goes in first: `(9 - 2) - 3`.
+    Equal<Four, <TCons<Three, TCons<Two, TNil>> as Fold<Nine, DifferenceFn>>::Output>;
+    Equal<Zero, <TNil as SumListAcc<Zero>>::Output>;
+    Equal<Six, <TCons<One, TCons<Two, TCons<Three, TNil>>> as SumListAcc<Zero>>::Output>;
+    Equal<Nine, <TCons<One, TCons<Two, TCons<Three, TNil>>> as SumListAcc<Three>>::Output>;
+    Equal<<SmallNats as SumList>::Output, <SmallNats as SumListAcc<Zero>>::Output>;
+    Equal<Three, <Zeros10<Zeros10<Zeros10<Zeros10<Zeros10<Zeros10<Zeros10<Zeros10<Zeros10<
+        Zeros10<Zeros10<Zeros10<Zeros10<Zeros10<TCons<One, TCons<Two, TNil>>>>>>>>>>>>>>>> as SumListAcc<Zero>>::Output>;
+}
+// 142 elements is too many to add up the naive way:
+// proof! { Equal<Three, <Zeros10<Zeros10<Zeros10<Zeros10<Zeros10<Zeros10<Zeros10<Zeros10<Zeros10<Zeros10<Zeros10<Zeros10<Zeros10<Zeros10<TCons<One, TCons<Two, TNil>>>>>>>>>>>>>>>> as SumList>::Output> }
+
+type SmallNats = TCons<Three, TCons<One, TCons<Four, TCons<One, TCons<Five, TCons<Nine, TCons<Two, TCons<Six,
+    TCons<Five, TCons<Three, TCons<Five, TCons<Eight, TCons<Nine, TCons<Seven, TCons<Nine, TNil>>>>>>>>>>>>>>>;
+type Zeros10<T> = TCons<Zero, TCons<Zero, TCons<Zero, TCons<Zero, TCons<Zero,
+    TCons<Zero, TCons<Zero, TCons<Zero, TCons<Zero, TCons<Zero, T>>>>>>>>>>;
+
+// A type-level `Either`, for type functions with two kinds of result.
+pub struct TLeft<L> { _l: PhantomData<L> }
+pub struct TRight<R> { _r: PhantomData<R> }
+
+/// Apply `F` to a `TLeft`, leaving a `TRight` alone.
+pub trait MapLeft<F> {
+    type Output;
+}
+impl<F: NatFn<L>, L> MapLeft<F> for TLeft<L> {
+    type Output = TLeft<<F as NatFn<L>>::Output>;
+}
+impl<F, R> MapLeft<F> for TRight<R> {
+    type Output = TRight<R>;
+}
+/// Apply `F` to a `TRight`, leaving a `TLeft` alone.
+pub trait MapRight<F> {
+    type Output;
+}
+impl<F, L> MapRight<F> for TLeft<L> {
+    type Output = TLeft<L>;
+}
+impl<F: NatFn<R>, R> MapRight<F> for TRight<R> {
+    type Output = TRight<<F as NatFn<R>>::Output>;
+}
+
+pub trait ReifyEither {
+    const OUTPUT: Result<u64, u64>;
+    fn val(&self) -> Result<u64, u64> { Self::OUTPUT }
+}
+impl<L: Nat + Reify> ReifyEither for TLeft<L> {
+    const OUTPUT: Result<u64, u64> = Ok(L::OUTPUT);
+}
+impl<R: Nat + Reify> ReifyEither for TRight<R> {
+    const OUTPUT: Result<u64, u64> = Err(R::OUTPUT);
+}
+
+proofs! {
+    Equal<TLeft<Four>, <TLeft<Two> as MapLeft<SquareFn>>::Output>;
+    Equal<TRight<Two>, <TRight<Two> as MapLeft<SquareFn>>::Output>;
+    Equal<TLeft<Two>, <TLeft<Two> as MapRight<SquareFn>>::Output>;
+    Equal<TRight<Nine>, <TRight<Three> as MapRight<SquareFn>>::Output>;
+}
+
+/// The list `[Self, Self + 1, ..., Hi - 1]`.
+pub trait Range<Hi> {
+    type Output;
+}
+impl<Lo: Nat, Hi: Nat> Range<Hi> for Lo where
+    Lo: Compare<Hi>,
+    Lo: RangeStep<Hi, <Lo as Compare<Hi>>::Output>,
+{
+    type Output = <Lo as RangeStep<Hi, <Lo as Compare<Hi>>::Output>>::Output;
+}
+pub trait RangeStep<Hi, Ordering> {
+    type Output;
+}
+impl<Lo: Nat, Hi: Nat> RangeStep<Hi, Less> for Lo where Successor<Lo>: Range<Hi> {
+    type Output = TCons<Lo, <Successor<Lo> as Range<Hi>>::Output>;
+}
+impl<Lo: Nat, Hi: Nat> RangeStep<Hi, EqualOrd> for Lo {
+    type Output = TNil;
+}
+impl<Lo: Nat, Hi: Nat> RangeStep<Hi, Greater> for Lo {
+    type Output = TNil;
+}
+pub type RangeList<Lo, Hi> = <Lo as Range<Hi>>::Output;
+
+/// Every natural below `Bound`, in order: `[0, 1, ..., Bound - 1]`.
+///
+/// This is the same list as `RangeList<Zero, Bound>`, but built by induction on `Bound`,
+/// as zero followed by the list below `Bound - 1` with one added to each element, so that
+/// facts about it can be proved by induction too. See `EnumerateLength`.
+pub trait Enumerate<Bound: Nat> {
+    type Output;
+}
+/// The naturals, for enumerating with `<Naturals as Enumerate<Bound>>::Output`.
+pub struct Naturals;
+impl Enumerate<Zero> for Naturals {
+    type Output = TNil;
+}
+impl<B: Nat> Enumerate<Successor<B>> for Naturals where
+    Naturals: Enumerate<B>,
+    <Naturals as Enumerate<B>>::Output: MapList<SuccessorFn>,
+{
+    type Output = TCons<Zero, <<Naturals as Enumerate<B>>::Output as MapList<SuccessorFn>>::Output>;
+}
+
+/// `Lo, Lo + 1, ..., Hi - 1`, like `RangeList`, but counted out at runtime
+/// instead of built as a type.
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// assert_eq!(NatRange::<Two, Five>::new().collect::<Vec<_>>(), vec![2, 3, 4]);
+/// assert_eq!(NatRange::<Zero, One>::new().collect::<Vec<_>>(), vec![0]);
+/// assert_eq!(NatRange::<Three, Three>::new().count(), 0);
+/// assert_eq!(NatRange::<Four, One>::new().count(), 0);
+/// assert_eq!(NatRange::<Zero, Nine>::new().sum::<u64>(), 36);
+/// ```
+pub struct NatRange<Lo: Nat, Hi: Nat> { next: u64, _bounds: PhantomData<(Lo, Hi)> }
+impl<Lo: Nat + Reify, Hi: Nat + Reify> NatRange<Lo, Hi> {
+    pub const fn new() -> Self {
+        NatRange { next: Lo::OUTPUT, _bounds: PhantomData }
+    }
+}
+impl<Lo: Nat + Reify, Hi: Nat + Reify> Default for NatRange<Lo, Hi> {
+    fn default() -> Self { Self::new() }
+}
+impl<Lo: Nat, Hi: Nat + Reify> Iterator for NatRange<Lo, Hi> {
+    type Item = u64;
+    fn next(&mut self) -> Option<u64> {
+        if self.next < Hi::OUTPUT {
+            self.next += 1;
+            Some(self.next - 1)
+        } else {
+            None
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = Hi::OUTPUT.saturating_sub(self.next) as usize;
+        (len, Some(len))
+    }
+}
+impl<Lo: Nat, Hi: Nat + Reify> ExactSizeIterator for NatRange<Lo, Hi> {}
+
+/// Apply `F` to every element of a list.
+pub trait MapList<F> {
+    type Output;
+}
+impl<F> MapList<F> for TNil {
+    type Output = TNil;
+}
+impl<F: NatFn<H>, H, T: MapList<F>> MapList<F> for TCons<H, T> {
+    type Output = TCons<<F as NatFn<H>>::Output, <T as MapList<F>>::Output>;
+}
+
+/// `n` goes to the `n`th odd number, `2n + 1`.
+pub struct OddFn;
+impl<N: Nat> NatFn<N> for OddFn where N: Sum<N>, <N as Sum<N>>::Output: Nat {
+    type Output = Successor<<N as Sum<N>>::Output>;
+}
+
+/// Row `n` of Pascal's triangle, `[C(n, 0), C(n, 1), ..., C(n, n)]`.
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// assert_eq!(PascalRow::<Four>::to_vec(), vec![1, 4, 6, 4, 1]);
+/// assert_eq!(PascalRow::<Zero>::to_vec(), vec![1]);
+/// ```
+pub type PascalRow<N> = <RangeList<Zero, Successor<N>> as MapList<ChooseFrom<N>>>::Output;
+
+proofs! {
+    Equal<RangeList<Two, Five>, TCons<Two, TCons<Three, TCons<Four, TNil>>>>;
+    Equal<RangeList<Three, Three>, TNil>;
+    Equal<RangeList<Four, One>, TNil>;
+    Equal<<RangeList<Zero, Three> as MapList<OddFn>>::Output, TCons<One, TCons<Three, TCons<Five, TNil>>>>;
+    // `GeometricSum` adds up the same powers as a list of them does:
+    Equal<<Three as GeometricSum<Two>>::Output, <<RangeList<Zero, Four> as MapList<PowerOf<Two>>>::Output as SumList>::Output>;
+    Equal<<Two as GeometricSum<Three>>::Output, <<RangeList<Zero, Three> as MapList<PowerOf<Three>>>::Output as SumList>::Output>;
+    Equal<<Naturals as Enumerate<Zero>>::Output, RangeList<Zero, Zero>>;
+    Equal<<Naturals as Enumerate<One>>::Output, RangeList<Zero, One>>;
+    Equal<<Naturals as Enumerate<Four>>::Output, RangeList<Zero, Four>>;
+    Equal<<Naturals as Enumerate<Nine>>::Output, RangeList<Zero, Nine>>;
+    Equal<Three, <RangeList<Two, Five> as ListLength>::Output>;
+    // Each row of Pascal's triangle adds up to `2ⁿ`:
+    Equal<<Two as Power<Zero>>::Output, <PascalRow<Zero> as SumList>::Output>;
+    Equal<<Two as Power<One>>::Output, <PascalRow<One> as SumList>::Output>;
+    Equal<<Two as Power<Two>>::Output, <PascalRow<Two> as SumList>::Output>;
+    Equal<<Two as Power<Three>>::Output, <PascalRow<Three> as SumList>::Output>;
+    Equal<<Two as Power<Four>>::Output, <PascalRow<Four> as SumList>::Output>;
+    Equal<<Two as Power<Five>>::Output, <PascalRow<Five> as SumList>::Output>;
+    Equal<<Two as Power<Six>>::Output, <PascalRow<Six> as SumList>::Output>;
+}
+// Row 7 adds up to 128, which is past rustc's recursion limit:
+// proof! { Equal<<Two as Power<Seven>>::Output, <PascalRow<Seven> as SumList>::Output> }
+
+// And the same, counted out:
+const _: () = assert!(<<PascalRow<Zero> as SumList>::Output as Reify>::OUTPUT == 1 << 0);
+const _: () = assert!(<<PascalRow<Three> as SumList>::Output as Reify>::OUTPUT == 1 << 3);
+const _: () = assert!(<<PascalRow<Five> as SumList>::Output as Reify>::OUTPUT == 1 << 5);
+const _: () = assert!(<<PascalRow<Six> as SumList>::Output as Reify>::OUTPUT == 1 << 6);
+
+// There are as many naturals below a bound as the bound says:
+const _: () = assert!(<<<Naturals as Enumerate<Zero>>::Output as ListLength>::Output as Reify>::OUTPUT == 0);
+const _: () = assert!(<<<Naturals as Enumerate<Five>>::Output as ListLength>::Output as Reify>::OUTPUT == 5);
+const _: () = assert!(<<<Naturals as Enumerate<Nine>>::Output as ListLength>::Output as Reify>::OUTPUT == 9);
@@ -0,0 +1,1501 @@
+//! Peano naturals, and arithmetic and decisions on them.
+
+use ::core::cmp::Ordering;
+use ::core::convert::TryFrom;
+use ::core::hash::{Hash, Hasher};
+use ::core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use ::alloc::{string::String, vec::Vec};
+
+use crate::proof::Equal;
+
+// A Peano-ish definition for natural numbers.
+pub trait Nat {}
+#[derive(Default)]
+pub struct Zero;
+impl Nat for Zero {}
+#[derive(Default)]
+pub struct Successor<N: Nat> { _n: PhantomData<N> }
+impl<N: Nat> Nat for Successor<N> {}
+pub trait Reify {
+    const OUTPUT: u64;
+    fn val(&self) -> u64 { Self::OUTPUT }
+}
+impl Reify for Zero {
+    const OUTPUT: u64 = 0;
+}
+impl<N: Nat + Reify> Reify for Successor<N> {
+    const OUTPUT: u64 = ReifyDepth::check(1 + N::OUTPUT);
+}
+
+/// The largest Peano natural `Reify` will give a value for.
+///
+/// Past `ReifyDepth::DEPTH`, evaluating `Reify::OUTPUT` fails with "number exceeds supported
+/// proof depth", and that's all this caps. `Nat` and the arithmetic traits don't consult it,
+/// and binary naturals, which are only about `log₂` of their value deep, aren't capped.
+///
+/// A Peano natural is as many `Successor`s deep as its value, and past rustc's default
+/// recursion limit of 128, just checking that it's a `Nat` overflows, in a solver error that
+/// doesn't mention your code. So the message only shows up for the few values between `DEPTH`
+/// and that limit. Anything bigger, and any sum or product that's too deep, still gets the
+/// solver's error.
+///
+/// This reifies, at exactly `DEPTH`:
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// type Deepest = <Eight as Product<<Five as Product<Three>>::Output>>::Output;
+/// assert_eq!(<Deepest as Reify>::OUTPUT, ReifyDepth::DEPTH);
+/// ```
+///
+/// and one more doesn't. rustdoc can only check that this fails with E0080, a const
+/// evaluation error, and not that the message is the cap's:
+///
+/// ```compile_fail,E0080
+/// use sqrt_irrational::prelude::*;
+/// type Deepest = <Eight as Product<<Five as Product<Three>>::Output>>::Output;
+/// const _: u64 = <Successor<Deepest> as Reify>::OUTPUT;
+/// ```
+pub struct ReifyDepth;
+impl ReifyDepth {
+    pub const DEPTH: u64 = 120;
+    const fn check(depth: u64) -> u64 {
+        assert!(depth <= Self::DEPTH, "number exceeds supported proof depth");
+        depth
+    }
+}
+
+// Compare naturals by value, so that different types which happen to
+// reify to the same number still count as equal.
+impl<B: Nat + Reify> PartialEq<B> for Zero {
+    fn eq(&self, _: &B) -> bool { Self::OUTPUT == B::OUTPUT }
+}
+impl<N: Nat + Reify, B: Nat + Reify> PartialEq<B> for Successor<N> {
+    fn eq(&self, _: &B) -> bool { Self::OUTPUT == B::OUTPUT }
+}
+impl Eq for Zero {}
+impl<N: Nat + Reify> Eq for Successor<N> {}
+impl<B: Nat + Reify> PartialOrd<B> for Zero {
+    fn partial_cmp(&self, _: &B) -> Option<Ordering> { Some(Self::OUTPUT.cmp(&B::OUTPUT)) }
+}
+impl<N: Nat + Reify, B: Nat + Reify> PartialOrd<B> for Successor<N> {
+    fn partial_cmp(&self, _: &B) -> Option<Ordering> { Some(Self::OUTPUT.cmp(&B::OUTPUT)) }
+}
+impl Ord for Zero {
+    fn cmp(&self, _: &Self) -> Ordering { Ordering::Equal }
+}
+impl<N: Nat + Reify> Ord for Successor<N> {
+    fn cmp(&self, _: &Self) -> Ordering { Ordering::Equal }
+}
+// Hash by value too, so that `Hash` agrees with `Eq`.
+impl Hash for Zero {
+    fn hash<H: Hasher>(&self, state: &mut H) { Self::OUTPUT.hash(state) }
+}
+impl<N: Nat + Reify> Hash for Successor<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) { Self::OUTPUT.hash(state) }
+}
+
+/// `Reify` has an associated const, so it can't be made into a trait object.
+/// This can, for when you want a `Vec<Box<dyn DynReify>>` or a map keyed by naturals.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use sqrt_irrational::prelude::*;
+/// use sqrt_irrational::DynReify;
+///
+/// let mut squares: HashMap<Box<dyn DynReify>, &str> = HashMap::new();
+/// squares.insert(Box::new(Zero), "zero");
+/// squares.insert(Box::new(<One as Square>::Output::default()), "one");
+/// squares.insert(Box::new(<Two as Square>::Output::default()), "four");
+/// // Four in binary is a different type, but the same number.
+/// let four: Box<dyn DynReify> = Box::new(PushZero::<PushZero<PushOne<BZero>>>::default());
+/// assert_eq!(squares.get(&four), Some(&"four"));
+/// assert_eq!(squares.get(&(Box::new(Three::default()) as Box<dyn DynReify>)), None);
+/// ```
+///
+/// They're ordered by value as well, so they sort, and work as `BTreeMap` keys, with binary and
+/// Peano naturals mixed:
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use sqrt_irrational::prelude::*;
+/// use sqrt_irrational::DynReify;
+///
+/// let mut naturals: Vec<Box<dyn DynReify>> = vec![
+///     Box::new(Five::default()),
+///     Box::new(PushOne::<BZero>::default()),
+///     Box::new(Three::default()),
+///     Box::new(Zero),
+/// ];
+/// naturals.sort();
+/// let values: Vec<u64> = naturals.iter().map(|n| n.dyn_val()).collect();
+/// assert_eq!(values, [0, 1, 3, 5]);
+///
+/// let mut names: BTreeMap<Box<dyn DynReify>, &str> = BTreeMap::new();
+/// names.insert(Box::new(Three::default()), "three");
+/// names.insert(Box::new(One::default()), "one");
+/// // Three in binary is the same key as three in Peano, so this replaces it.
+/// names.insert(Box::new(PushOne::<PushOne<BZero>>::default()), "binary three");
+/// assert_eq!(names.len(), 2);
+/// assert_eq!(names.values().collect::<Vec<_>>(), [&"one", &"binary three"]);
+/// ```
+pub trait DynReify {
+    fn dyn_val(&self) -> u64;
+}
+impl<T: Reify> DynReify for T {
+    fn dyn_val(&self) -> u64 { T::OUTPUT }
+}
+impl PartialEq for dyn DynReify {
+    fn eq(&self, other: &Self) -> bool { self.dyn_val() == other.dyn_val() }
+}
+impl Eq for dyn DynReify {}
+impl PartialOrd for dyn DynReify {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+impl Ord for dyn DynReify {
+    fn cmp(&self, other: &Self) -> Ordering { self.dyn_val().cmp(&other.dyn_val()) }
+}
+impl Hash for dyn DynReify {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.dyn_val().hash(state) }
+}
+
+/// The biggest number `TryReify` will reify.
+///
+/// It's kept below `ReifyDepth::DEPTH`, so anything `TryReify` accepts also reifies with `Reify`,
+/// and `TryReify` reports the error before `Reify` would fail the build.
+const REIFY_LIMIT: u64 = 64;
+const _: () = assert!(REIFY_LIMIT < ReifyDepth::DEPTH);
+
+/// Why a natural couldn't be reified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReifyError {
+    /// The number is bigger than `REIFY_LIMIT`.
+    TooLarge,
+}
+impl ::core::fmt::Display for ReifyError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            ReifyError::TooLarge => write!(f, "natural is larger than the reify limit of {}", REIFY_LIMIT),
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl ::std::error::Error for ReifyError {}
+
+/// A runtime number that was bigger than a `nat_from_runtime!` was ready for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfRange {
+    pub value: u64,
+    pub max: u64,
+}
+impl ::core::fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(f, "{} is larger than the bound of {}", self.value, self.max)
+    }
+}
+#[cfg(feature = "std")]
+impl ::std::error::Error for OutOfRange {}
+
+/// Like `Reify`, but gives up on numbers past `REIFY_LIMIT`, so that building one
+/// too big shows up as an error you can look at.
+pub trait TryReify {
+    const TRY_OUTPUT: Result<u64, ReifyError>;
+    fn try_val() -> Result<u64, ReifyError> { Self::TRY_OUTPUT }
+}
+impl TryReify for Zero {
+    const TRY_OUTPUT: Result<u64, ReifyError> = Ok(0);
+}
+impl<N: Nat + TryReify> TryReify for Successor<N> {
+    const TRY_OUTPUT: Result<u64, ReifyError> = match N::TRY_OUTPUT {
+        Ok(n) if n < REIFY_LIMIT => Ok(n + 1),
+        _ => Err(ReifyError::TooLarge),
+    };
+}
+
+const _: () = assert!(matches!(<Nine as TryReify>::TRY_OUTPUT, Ok(9)));
+const _: () = assert!(matches!(<<Eight as Square>::Output as TryReify>::TRY_OUTPUT, Ok(64)));
+const _: () = assert!(matches!(
+    <Successor<<Eight as Square>::Output> as TryReify>::TRY_OUTPUT,
+    Err(ReifyError::TooLarge)
+));
+
+/// Reify into any integer type, rather than just `u64`.
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// assert_eq!(Four::reify_as::<u8>(), 4u8);
+/// assert_eq!(Four::reify_as::<i16>(), 4i16);
+/// assert_eq!(Four::reify_as::<usize>(), 4usize);
+/// assert_eq!(Four::try_reify_as::<u128>(), Some(4u128));
+/// // Binary 100000000, or 256, doesn't fit in a `u8`.
+/// type TwoFiftySix = PushZero<PushZero<PushZero<PushZero<PushZero<PushZero<PushZero<PushZero<PushOne<BZero>>>>>>>>>;
+/// assert_eq!(TwoFiftySix::try_reify_as::<u8>(), None);
+/// assert_eq!(TwoFiftySix::try_reify_as::<u16>(), Some(256));
+/// ```
+pub trait ReifyAs: Reify {
+    /// `None` if the number doesn't fit in a `T`.
+    fn try_reify_as<T: TryFrom<u64>>() -> Option<T> { T::try_from(Self::OUTPUT).ok() }
+    /// Panics if the number doesn't fit in a `T`.
+    fn reify_as<T: TryFrom<u64>>() -> T {
+        Self::try_reify_as().expect("natural doesn't fit in the integer type")
+    }
+}
+impl<N: Reify> ReifyAs for N {}
+
+/// Reify `N` and write it out in base `radix`, with lowercase letters for digits past `9`.
+/// Binary naturals work too, which is handy when they get long.
+///
+/// Panics if `radix` isn't between 2 and 36, like `char::from_digit` does.
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// assert_eq!(to_string_radix::<Zero>(2), "0");
+/// assert_eq!(to_string_radix::<Six>(2), "110");
+/// assert_eq!(to_string_radix::<<Nine as Square>::Output>(10), "81");
+/// assert_eq!(to_string_radix::<<Nine as Square>::Output>(16), "51");
+/// type FiftyNine = PushOne<PushOne<PushZero<PushOne<PushOne<PushOne<BZero>>>>>>;
+/// assert_eq!(to_string_radix::<FiftyNine>(16), "3b");
+/// assert_eq!(to_string_radix::<FiftyNine>(36), "1n");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn to_string_radix<N: Reify>(radix: u32) -> String {
+    assert!((2..=36).contains(&radix), "radix must be between 2 and 36");
+    let mut n = N::OUTPUT;
+    let mut digits = Vec::new();
+    loop {
+        digits.push(::core::char::from_digit((n % radix as u64) as u32, radix).unwrap());
+        n /= radix as u64;
+        if n == 0 { break; }
+    }
+    digits.iter().rev().collect()
+}
+
+/// Addition!
+pub trait Sum<Addend: Nat> {
+    type Output;
+}
+impl<A: Nat> Sum<Zero> for A {
+    type Output = A;
+}
+impl<A: Nat, B: Nat> Sum<Successor<B>> for A where Successor<A>: Sum<B> {
+    type Output = <Successor<A> as Sum<B>>::Output;
+}
+
+/// Subtraction!
+pub trait Difference<Subtrahend: Nat> {
+    type Output;
+}
+impl<A: Nat> Difference<Zero> for A {
+    type Output = A;
+}
+impl<A: Nat, B: Nat> Difference<Successor<B>> for Successor<A> where A: Difference<B> {
+    type Output = <A as Difference<B>>::Output;
+}
+
+pub trait Product<Multiplicand> {
+    type Output;
+}
+impl<A> Product<Zero> for A {
+    type Output = Zero;
+}
+impl<A: Nat, B: Nat> Product<Successor<B>> for A where A: Product<B>, <A as Product<B>>::Output: Sum<A> {
+    type Output = <<A as Product<B>>::Output as Sum<A>>::Output;
+}
+
+/// Exponentiation!
+pub trait Power<Exponent: Nat> {
+    type Output;
+}
+impl<A: Nat> Power<Zero> for A {
+    type Output = One;
+}
+impl<A: Nat, B: Nat> Power<Successor<B>> for A where A: Power<B>, <A as Power<B>>::Output: Product<A> {
+    type Output = <<A as Power<B>>::Output as Product<A>>::Output;
+}
+
+pub trait Square {
+    type Output;
+}
+impl<A: Nat> Square for A where A: Product<A> {
+    type Output = <A as Product<A>>::Output;
+}
+
+/// Triangle numbers, `T(n) = 1 + 2 + ... + n`.
+/// That's also how many handshakes it takes for `n + 1` people to all meet.
+pub trait Triangular {
+    type Output: Nat;
+}
+impl Triangular for Zero {
+    type Output = Zero;
+}
+impl<N: Nat> Triangular for Successor<N> where
+    N: Triangular,
+    <N as Triangular>::Output: Sum<Successor<N>>,
+    <<N as Triangular>::Output as Sum<Successor<N>>>::Output: Nat,
+{
+    type Output = <<N as Triangular>::Output as Sum<Successor<N>>>::Output;
+}
+
+/// `n! = 1·2·...·n`.
+pub trait Factorial {
+    type Output: Nat;
+}
+impl Factorial for Zero {
+    type Output = One;
+}
+impl<N: Nat> Factorial for Successor<N> where
+    N: Factorial,
+    <N as Factorial>::Output: Product<Successor<N>>,
+    <<N as Factorial>::Output as Product<Successor<N>>>::Output: Nat,
+{
+    type Output = <<N as Factorial>::Output as Product<Successor<N>>>::Output;
+}
+
+/// `C(n, k)`, the number of ways to pick `k` things out of `n`, with `Self` as `n`.
+///
+/// Built by Pascal's rule, `C(n + 1, k + 1) = C(n, k) + C(n, k + 1)`, which takes both
+/// branches all the way down, so it gets slow well before the numbers get big.
+pub trait Choose<K: Nat> {
+    type Output: Nat;
+}
+impl<N: Nat> Choose<Zero> for N {
+    type Output = One;
+}
+impl<K: Nat> Choose<Successor<K>> for Zero {
+    type Output = Zero;
+}
+impl<N: Nat, K: Nat> Choose<Successor<K>> for Successor<N> where
+    N: Choose<K> + Choose<Successor<K>>,
+    <N as Choose<K>>::Output: Sum<<N as Choose<Successor<K>>>::Output>,
+    <<N as Choose<K>>::Output as Sum<<N as Choose<Successor<K>>>::Output>>::Output: Nat,
+{
+    type Output = <<N as Choose<K>>::Output as Sum<<N as Choose<Successor<K>>>::Output>>::Output;
+}
+
+/// Consecutive Fibonacci numbers, `(F(n), F(n + 1))`, with `Self` as `n`.
+///
+/// Carrying the pair along means each step is one addition.
+pub trait FibonacciPair {
+    type Current: Nat;
+    type Next: Nat;
+}
+impl FibonacciPair for Zero {
+    type Current = Zero;
+    type Next = One;
+}
+impl<N: Nat> FibonacciPair for Successor<N> where
+    N: FibonacciPair,
+    <N as FibonacciPair>::Current: Sum<<N as FibonacciPair>::Next>,
+    <<N as FibonacciPair>::Current as Sum<<N as FibonacciPair>::Next>>::Output: Nat,
+{
+    type Current = <N as FibonacciPair>::Next;
+    type Next = <<N as FibonacciPair>::Current as Sum<<N as FibonacciPair>::Next>>::Output;
+}
+/// The Fibonacci numbers, `0, 1, 1, 2, 3, 5, 8, ...`.
+pub trait Fibonacci {
+    type Output: Nat;
+}
+impl<N: FibonacciPair> Fibonacci for N {
+    type Output = <N as FibonacciPair>::Current;
+}
+
+/// The geometric series `1 + r + r² + ... + rⁿ`, with `Self` as `n`.
+pub trait GeometricSum<R: Nat> {
+    type Output: Nat;
+}
+impl<R: Nat> GeometricSum<R> for Zero {
+    type Output = One;
+}
+impl<R: Nat, N: Nat> GeometricSum<R> for Successor<N> where
+    N: GeometricSum<R>,
+    R: Power<Successor<N>>,
+    <N as GeometricSum<R>>::Output: Sum<<R as Power<Successor<N>>>::Output>,
+    <R as Power<Successor<N>>>::Output: Nat,
+    <<N as GeometricSum<R>>::Output as Sum<<R as Power<Successor<N>>>::Output>>::Output: Nat,
+{
+    type Output = <<N as GeometricSum<R>>::Output as Sum<<R as Power<Successor<N>>>::Output>>::Output;
+}
+
+/// A natural as a value, so the arithmetic can be written with `+`, `-` and `*`.
+/// The result's type is the answer.
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// let five: NatValue<Five> = NatValue::<Two>::default() + NatValue::<Three>::default();
+/// let six: NatValue<Six> = five * NatValue::<One>::default() + NatValue::<One>::default();
+/// let four: NatValue<Four> = six - NatValue::<Two>::default();
+/// assert_eq!(four.val(), 4);
+/// ```
+///
+/// There's no natural below zero, so this doesn't compile:
+///
+/// ```compile_fail
+/// use sqrt_irrational::prelude::*;
+/// let _ = NatValue::<Two>::default() - NatValue::<Three>::default();
+/// ```
+///
+/// Iterators can sum and multiply them too, but an iterator's items all have one type, and
+/// how many there are isn't known until it runs. So `[NatValue::<One>, NatValue::<Two>]`
+/// isn't an array at all (a type-level list and [`SumList`](crate::SumList) is the way to add
+/// different naturals), and the only sums with a type of their own are the ones that can't
+/// depend on the count: any number of zeros add up to zero, and any number of ones multiply
+/// to one. Anything else reifies into a `u64` as it goes.
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// let zero: NatValue<Zero> = [NatValue::<Zero>::new(); 4].iter().copied().sum();
+/// let one: NatValue<One> = [NatValue::<One>::new(); 3].iter().copied().product();
+/// assert_eq!((zero.val(), one.val()), (0, 1));
+///
+/// let threes = [NatValue::<Three>::new(); 4];
+/// assert_eq!(threes.iter().copied().sum::<u64>(), 12);
+/// assert_eq!(threes.iter().copied().product::<u64>(), 81);
+/// assert_eq!(threes[..0].iter().copied().product::<u64>(), 1);
+/// ```
+///
+/// ```compile_fail
+/// use sqrt_irrational::prelude::*;
+/// let _: NatValue<Six> = [NatValue::<Three>::new(); 2].iter().copied().sum();
+/// ```
+pub struct NatValue<N> { _n: PhantomData<N> }
+impl<N> NatValue<N> {
+    pub const fn new() -> Self { NatValue { _n: PhantomData } }
+}
+impl<N> Default for NatValue<N> {
+    fn default() -> Self { Self::new() }
+}
+impl<N> Clone for NatValue<N> {
+    fn clone(&self) -> Self { *self }
+}
+impl<N> Copy for NatValue<N> {}
+impl<N: Reify> Reify for NatValue<N> {
+    const OUTPUT: u64 = N::OUTPUT;
+}
+// Operator lookup asks for `NatValue<A> + ?` before it knows what `?` is, and rustc would try
+// every natural for it in the meantime. Putting the unknown first makes it wait instead.
+impl<A, B> ::core::ops::Add<NatValue<B>> for NatValue<A> where B: AppliedTo<SumFn, A> {
+    type Output = NatValue<<B as AppliedTo<SumFn, A>>::Output>;
+    fn add(self, _: NatValue<B>) -> Self::Output { NatValue::new() }
+}
+impl<A, B> ::core::ops::Sub<NatValue<B>> for NatValue<A> where B: AppliedTo<DifferenceFn, A> {
+    type Output = NatValue<<B as AppliedTo<DifferenceFn, A>>::Output>;
+    fn sub(self, _: NatValue<B>) -> Self::Output { NatValue::new() }
+}
+impl<A, B> ::core::ops::Mul<NatValue<B>> for NatValue<A> where B: AppliedTo<ProductFn, A> {
+    type Output = NatValue<<B as AppliedTo<ProductFn, A>>::Output>;
+    fn mul(self, _: NatValue<B>) -> Self::Output { NatValue::new() }
+}
+impl ::core::iter::Sum for NatValue<Zero> {
+    fn sum<I: Iterator<Item = Self>>(_: I) -> Self { NatValue::new() }
+}
+impl ::core::iter::Product for NatValue<One> {
+    fn product<I: Iterator<Item = Self>>(_: I) -> Self { NatValue::new() }
+}
+// Going through `u64`'s own `Sum` and `Product` so an overflowing total is caught the same way.
+impl<N: Reify> ::core::iter::Sum<NatValue<N>> for u64 {
+    fn sum<I: Iterator<Item = NatValue<N>>>(iter: I) -> Self { iter.map(|_| N::OUTPUT).sum() }
+}
+impl<N: Reify> ::core::iter::Product<NatValue<N>> for u64 {
+    fn product<I: Iterator<Item = NatValue<N>>>(iter: I) -> Self { iter.map(|_| N::OUTPUT).product() }
+}
+
+// LessThan<N> is essentially an alias for `N: Difference<Successor<Self>>`
+/// Less than comparison.
+pub trait LessThan<N> {
+    // This has no result, but I'd like it to be easily usable with
+    // the `proof!` macro, which works by asserting a type is valid.
+    type Output;
+}
+impl<A: Nat, B: Nat> LessThan<B> for A where
+    B: Difference<Successor<A>>,
+{
+    type Output = ();
+}
+
+pub trait LessThanOrEqual<N> {
+    type Output;
+}
+impl<A: Nat, B: Nat> LessThanOrEqual<B> for A where
+    B: Difference<A>,
+{
+    type Output = ();
+}
+
+pub trait GreaterThan<N> {
+    type Output;
+}
+impl<A: Nat, B: Nat> GreaterThan<B> for A where
+    A: Difference<Successor<B>>,
+{
+    type Output = ();
+}
+
+pub trait GreaterThanOrEqual<N> {
+    type Output;
+}
+impl<A: Nat, B: Nat> GreaterThanOrEqual<B> for A where
+    A: Difference<B>,
+{
+    type Output = ();
+}
+
+// These fail to compile, as you'd expect:
+// proof! { <Zero as Difference<One>>::Output }
+// proof! { <Zero as LessThan<Zero>>::Output }
+
+// Here are some proofs I expect to work.
+// If they don't, my axioms are wrong.
+proofs! {
+    // Addition tests:
+    Equal<Two, <One as Sum<One>>::Output>;
+    Equal<Three, <One as Sum<Two>>::Output>;
+    
+    // Subtraction tests:
+    Equal<Two, <Three as Difference<One>>::Output>;
+    Equal<Zero, <Four as Difference<Four>>::Output>;
+    Equal<Zero, <Zero as Difference<Zero>>::Output>;
+    
+    // Multiplication tests:
+    Equal<One, <One as Product<One>>::Output>;
+    Equal<Four, <Two as Product<Two>>::Output>;
+    Equal<Six, <Two as Product<Three>>::Output>;
+
+    // Exponentiation tests:
+    Equal<One, <Five as Power<Zero>>::Output>;
+    Equal<Eight, <Two as Power<Three>>::Output>;
+    Equal<Nine, <Three as Power<Two>>::Output>;
+
+    // Triangle number tests:
+    Equal<Zero, <Zero as Triangular>::Output>;
+    Equal<One, <One as Triangular>::Output>;
+    Equal<Six, <Three as Triangular>::Output>;
+
+    // Factorial tests:
+    Equal<One, <Zero as Factorial>::Output>;
+    Equal<One, <One as Factorial>::Output>;
+    Equal<Six, <Three as Factorial>::Output>;
+
+    // Binomial coefficient tests:
+    Equal<One, <Zero as Choose<Zero>>::Output>;
+    Equal<Zero, <Two as Choose<Three>>::Output>;
+    Equal<Six, <Four as Choose<Two>>::Output>;
+    Equal<<Six as Choose<Two>>::Output, <Six as Choose<Four>>::Output>;
+
+    // Fibonacci tests:
+    Equal<Zero, <Zero as Fibonacci>::Output>;
+    Equal<One, <Two as Fibonacci>::Output>;
+    Equal<Eight, <Six as Fibonacci>::Output>;
+
+    // Geometric series tests:
+    Equal<One, <Zero as GeometricSum<Five>>::Output>;
+    Equal<Seven, <Two as GeometricSum<Two>>::Output>;
+    Equal<<Three as Sum<One>>::Output, <Three as GeometricSum<One>>::Output>;
+}
+
+// The same kind of checks, by value.
+static_assert_nat_eq!(<Two as Sum<Three>>::Output, <Three as Sum<Two>>::Output);
+static_assert_nat_eq!(<Four as Product<Two>>::Output, <Two as Power<Three>>::Output);
+static_assert_nat_eq!(<Nine as Difference<Nine>>::Output, Zero);
+
+
+/// Booleans, for type-level decisions.
+pub trait Bool {
+    const OUTPUT: bool;
+}
+pub struct True;
+impl Bool for True {
+    const OUTPUT: bool = true;
+}
+pub struct False;
+impl Bool for False {
+    const OUTPUT: bool = false;
+}
+
+/// `¬a`.
+pub trait Not: Bool {
+    type Output: Bool;
+}
+impl Not for True {
+    type Output = False;
+}
+impl Not for False {
+    type Output = True;
+}
+/// `a ∧ b`.
+pub trait And<B: Bool>: Bool {
+    type Output: Bool;
+}
+impl<B: Bool> And<B> for True {
+    type Output = B;
+}
+impl<B: Bool> And<B> for False {
+    type Output = False;
+}
+/// `a ∨ b`.
+pub trait Or<B: Bool>: Bool {
+    type Output: Bool;
+}
+impl<B: Bool> Or<B> for True {
+    type Output = True;
+}
+impl<B: Bool> Or<B> for False {
+    type Output = B;
+}
+
+/// Type-level `if`. Note that both branches are plain types, so this can't
+/// be used to guard a branch which doesn't exist. For that, dispatch on the
+/// condition with a helper trait instead.
+pub trait If<Then, Else> {
+    type Output;
+}
+impl<Then, Else> If<Then, Else> for True {
+    type Output = Then;
+}
+impl<Then, Else> If<Then, Else> for False {
+    type Output = Else;
+}
+
+/// Decidable equality. Unlike `Congruent`, this gives an answer either way.
+pub trait TypeEq<B> {
+    type Output: Bool;
+}
+impl TypeEq<Zero> for Zero {
+    type Output = True;
+}
+impl<B: Nat> TypeEq<Successor<B>> for Zero {
+    type Output = False;
+}
+impl<A: Nat> TypeEq<Zero> for Successor<A> {
+    type Output = False;
+}
+impl<A: Nat, B: Nat> TypeEq<Successor<B>> for Successor<A> where A: TypeEq<B> {
+    type Output = <A as TypeEq<B>>::Output;
+}
+// And for `Bool`s, which is `a ↔ b`.
+impl<B: Bool> TypeEq<B> for True {
+    type Output = B;
+}
+impl<B: Bool + Not> TypeEq<B> for False {
+    type Output = <B as Not>::Output;
+}
+
+/// The possible results of a three-way comparison.
+///
+/// These are only tags. Nothing about them says what kind of type gets picked with them,
+/// so `SelectOrd` and `match_ord!` can pick between a ratio and a list just as well as
+/// between two naturals.
+pub trait Comparison {}
+pub struct Less;
+pub struct EqualOrd;
+pub struct Greater;
+impl Comparison for Less {}
+impl Comparison for EqualOrd {}
+impl Comparison for Greater {}
+
+/// Three-way comparison.
+pub trait Compare<B> {
+    type Output: Comparison;
+}
+impl Compare<Zero> for Zero {
+    type Output = EqualOrd;
+}
+impl<B: Nat> Compare<Successor<B>> for Zero {
+    type Output = Less;
+}
+impl<A: Nat> Compare<Zero> for Successor<A> {
+    type Output = Greater;
+}
+impl<A: Nat, B: Nat> Compare<Successor<B>> for Successor<A> where A: Compare<B> {
+    type Output = <A as Compare<B>>::Output;
+}
+
+/// Type-level `match` on a comparison. Like `If`, every arm is a plain type,
+/// and the arms don't need anything in common:
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// // `a/b` if that's below one, and otherwise the list `[a, b]`.
+/// type RatioOrPair<A, B> = match_ord!(<A as Compare<B>>::Output => {
+///     Less => Ratio<A, B>,
+///     EqualOrd => TCons<A, TCons<B, TNil>>,
+///     Greater => TCons<A, TCons<B, TNil>>,
+/// });
+/// assert_eq!(<RatioOrPair<Two, Three> as ReifyRatio>::OUTPUT, (2, 3));
+/// assert_eq!(<RatioOrPair<Three, Two> as ReifyList>::iter().collect::<Vec<_>>(), [3, 2]);
+/// ```
+pub trait SelectOrd<IfLess, IfEqual, IfGreater> {
+    type Output;
+}
+impl<L, E, G> SelectOrd<L, E, G> for Less {
+    type Output = L;
+}
+impl<L, E, G> SelectOrd<L, E, G> for EqualOrd {
+    type Output = E;
+}
+impl<L, E, G> SelectOrd<L, E, G> for Greater {
+    type Output = G;
+}
+
+/// The smaller of two naturals.
+pub trait Min<B> {
+    type Output;
+}
+impl<A: Nat, B: Nat> Min<B> for A where
+    A: Compare<B>,
+    <A as Compare<B>>::Output: SelectOrd<A, A, B>,
+{
+    type Output = match_ord!(<A as Compare<B>>::Output => { Less => A, EqualOrd => A, Greater => B });
+}
+/// The larger of two naturals.
+pub trait Max<B> {
+    type Output;
+}
+impl<A: Nat, B: Nat> Max<B> for A where
+    A: Compare<B>,
+    <A as Compare<B>>::Output: SelectOrd<B, A, A>,
+{
+    type Output = match_ord!(<A as Compare<B>>::Output => { Less => B, EqualOrd => A, Greater => A });
+}
+/// `Self`, pulled into the range `Lo..=Hi`.
+pub trait Clamp<Lo, Hi> {
+    type Output;
+}
+impl<A: Nat, Lo: Nat, Hi: Nat> Clamp<Lo, Hi> for A where
+    A: Max<Lo>,
+    <A as Max<Lo>>::Output: Min<Hi>,
+{
+    type Output = <<A as Max<Lo>>::Output as Min<Hi>>::Output;
+}
+
+/// Division, rounding down. There's no impl for dividing by `Zero`.
+pub trait Quotient<Divisor: Nat> {
+    type Output: Nat;
+}
+impl<A: Nat, D: Nat> Quotient<Successor<D>> for A where
+    A: Compare<Successor<D>>,
+    A: QuotientStep<Successor<D>, <A as Compare<Successor<D>>>::Output>,
+{
+    type Output = <A as QuotientStep<Successor<D>, <A as Compare<Successor<D>>>::Output>>::Output;
+}
+pub trait QuotientStep<Divisor: Nat, Ordering> {
+    type Output: Nat;
+}
+impl<A: Nat, D: Nat> QuotientStep<D, Less> for A {
+    type Output = Zero;
+}
+impl<A: Nat, D: Nat> QuotientStep<D, EqualOrd> for A {
+    type Output = One;
+}
+impl<A: Nat, D: Nat> QuotientStep<D, Greater> for A where
+    A: Difference<D>,
+    <A as Difference<D>>::Output: Quotient<D>,
+{
+    type Output = Successor<<<A as Difference<D>>::Output as Quotient<D>>::Output>;
+}
+
+/// Remainder of division. There's no impl for dividing by `Zero`.
+pub trait Remainder<Divisor: Nat> {
+    type Output: Nat;
+}
+impl<A: Nat, D: Nat> Remainder<Successor<D>> for A where
+    A: Compare<Successor<D>>,
+    A: RemainderStep<Successor<D>, <A as Compare<Successor<D>>>::Output>,
+{
+    type Output = <A as RemainderStep<Successor<D>, <A as Compare<Successor<D>>>::Output>>::Output;
+}
+// Dispatching on the comparison keeps us from ever asking for `A - D` when `A < D`.
+pub trait RemainderStep<Divisor: Nat, Ordering> {
+    type Output: Nat;
+}
+impl<A: Nat, D: Nat> RemainderStep<D, Less> for A {
+    type Output = A;
+}
+impl<A: Nat, D: Nat> RemainderStep<D, EqualOrd> for A {
+    type Output = Zero;
+}
+impl<A: Nat, D: Nat> RemainderStep<D, Greater> for A where
+    A: Difference<D>,
+    <A as Difference<D>>::Output: Remainder<D>,
+{
+    type Output = <<A as Difference<D>>::Output as Remainder<D>>::Output;
+}
+
+/// Greatest common divisor, by Euclid's algorithm.
+pub trait Gcd<B> {
+    type Output: Nat;
+}
+impl<A: Nat> Gcd<Zero> for A {
+    type Output = A;
+}
+impl<A: Nat, B: Nat> Gcd<Successor<B>> for A where
+    A: Remainder<Successor<B>>,
+    Successor<B>: Gcd<<A as Remainder<Successor<B>>>::Output>,
+{
+    type Output = <Successor<B> as Gcd<<A as Remainder<Successor<B>>>::Output>>::Output;
+}
+
+/// Being able to write `<D as Divides<N>>::Output` is proof that `D` divides `N`.
+pub trait Divides<N> {
+    type Output;
+}
+impl Divides<Zero> for Zero {
+    type Output = ();
+}
+impl<D: Nat, N: Nat> Divides<N> for Successor<D> where N: Remainder<Successor<D>, Output = Zero> {
+    type Output = ();
+}
+
+/// Being able to write `<A as Coprime<B>>::Output` is proof that `gcd(A, B) = 1`.
+pub trait Coprime<B> {
+    type Output;
+}
+impl<A: Nat, B: Nat> Coprime<B> for A where A: Gcd<B, Output = One> {
+    type Output = ();
+}
+/// Decidable version of `Coprime`.
+pub trait IsCoprime<B> {
+    type Output: Bool;
+}
+impl<A: Nat, B: Nat> IsCoprime<B> for A where
+    A: Gcd<B>,
+    <A as Gcd<B>>::Output: TypeEq<One>,
+{
+    type Output = <<A as Gcd<B>>::Output as TypeEq<One>>::Output;
+}
+
+/// Decidable evenness.
+pub trait Parity {
+    type IsEven: Bool;
+}
+/// `True` if `N` is even, and `False` if it's odd.
+pub type IsEven<N> = <N as Parity>::IsEven;
+// A Peano natural has to be walked all the way down to find its remainder.
+impl<N: Nat> Parity for N where
+    N: Remainder<Two>,
+    <N as Remainder<Two>>::Output: TypeEq<Zero>,
+{
+    type IsEven = <<N as Remainder<Two>>::Output as TypeEq<Zero>>::Output;
+}
+
+/// Evenness and oddness as properties rather than a function, defined by flipping at each
+/// successor. `Zero` isn't `Odd`, so nothing is both: see `ParityTotal` for the proof.
+///
+/// ```compile_fail
+/// use sqrt_irrational::prelude::*;
+/// fn both<N: Even + Odd>() {}
+/// both::<Four>();
+/// ```
+pub trait Even: Nat {}
+pub trait Odd: Nat {}
+impl Even for Zero {}
+impl<N: Odd> Even for Successor<N> {}
+impl<N: Even> Odd for Successor<N> {}
+
+// Trait aliases for the bounds generic code asks for most, so that a signature can say
+// `A: CanSubtract<B>` rather than spelling out which traits that takes. Real trait aliases
+// aren't stable, so each one is a trait with the bounds as supertraits, implemented for
+// everything that meets them. Supertraits get assumed wherever the alias is, so a function
+// bounded by one can use anything they provide.
+
+/// `A: CanAdd<B>` when `A + B` is defined, which it is for any two naturals.
+pub trait CanAdd<B: Nat>: Nat + Sum<B> {}
+impl<A: Nat + Sum<B>, B: Nat> CanAdd<B> for A {}
+
+/// `A: CanSubtract<B>` when `A - B` is defined, i.e. when `A ≥ B`.
+pub trait CanSubtract<B: Nat>: Nat + Difference<B> + GreaterThanOrEqual<B> {}
+impl<A: Nat + Difference<B>, B: Nat> CanSubtract<B> for A {}
+
+/// Anything but `Zero`, i.e. `Successor<_>`. Its predecessor is `<N as Difference<One>>::Output`.
+///
+/// ```compile_fail
+/// use sqrt_irrational::prelude::*;
+/// fn positive<N: NonZero>() {}
+/// positive::<Zero>();
+/// ```
+pub trait NonZero: Nat + Difference<One> + GreaterThan<Zero> {}
+impl<N: Nat> NonZero for Successor<N> {}
+
+/// `A: CoprimeWith<B>` when `gcd(A, B) = 1`, which a function bounded by it gets to assume.
+pub trait CoprimeWith<B: Nat>: Nat + Gcd<B, Output = One> + Coprime<B> {}
+impl<A: Nat + Gcd<B, Output = One>, B: Nat> CoprimeWith<B> for A {}
+
+const fn sum_of<A: CanAdd<B>, B: Nat>() -> u64 where <A as Sum<B>>::Output: Reify {
+    <<A as Sum<B>>::Output as Reify>::OUTPUT
+}
+const fn difference_of<A: CanSubtract<B>, B: Nat>() -> u64 where <A as Difference<B>>::Output: Reify {
+    <<A as Difference<B>>::Output as Reify>::OUTPUT
+}
+const fn predecessor<N: NonZero>() -> u64 where <N as Difference<One>>::Output: Reify {
+    <<N as Difference<One>>::Output as Reify>::OUTPUT
+}
+// Nothing needs to say the gcd can be reified, since `CoprimeWith` already says it's `One`.
+const fn coprime_gcd<A: CoprimeWith<B>, B: Nat>() -> u64 {
+    <<A as Gcd<B>>::Output as Reify>::OUTPUT
+}
+#[crate::attr::proof]
+fn coprime_gcd_is_one<A: CoprimeWith<B>, B: Nat>() -> Equal<One, <A as Gcd<B>>::Output> {}
+
+const _: () = assert!(sum_of::<Two, Three>() == 5);
+const _: () = assert!(difference_of::<Five, Two>() == 3);
+const _: () = assert!(difference_of::<Four, Four>() == 0);
+const _: () = assert!(predecessor::<One>() == 0);
+const _: () = assert!(predecessor::<Nine>() == 8);
+const _: () = assert!(coprime_gcd::<Four, Nine>() == 1);
+// These fail to compile:
+// const _: () = assert!(difference_of::<Two, Five>() == 0);
+// const _: () = assert!(predecessor::<Zero>() == 0);
+// const _: () = assert!(coprime_gcd::<Four, Six>() == 1);
+
+proofs! {
+    // Comparison tests:
+    Equal<Less, <One as Compare<Three>>::Output>;
+    Equal<EqualOrd, <Three as Compare<Three>>::Output>;
+    Equal<Greater, <Four as Compare<Two>>::Output>;
+    Equal<True, <Two as TypeEq<Two>>::Output>;
+    Equal<False, <Two as TypeEq<Five>>::Output>;
+
+    // Dispatch macro tests:
+    Equal<One, match_ord!(<Two as Compare<Five>>::Output => { Less => One, EqualOrd => Two, Greater => Three })>;
+    Equal<Two, match_ord!(<Five as Compare<Five>>::Output => { Less => One, EqualOrd => Two, Greater => Three })>;
+    Equal<Three, match_ord!(<Five as Compare<Two>>::Output => { Less => One, EqualOrd => Two, Greater => Three })>;
+    Equal<Four, match_bool!(<Two as TypeEq<Two>>::Output => { True => Four, False => Five })>;
+    Equal<Five, match_bool!(<Two as TypeEq<Six>>::Output => { True => Four, False => Five })>;
+
+    // Min, max and clamp tests:
+    Equal<Two, <Two as Min<Five>>::Output>;
+    Equal<Two, <Five as Min<Two>>::Output>;
+    Equal<Five, <Two as Max<Five>>::Output>;
+    Equal<Three, <Three as Max<Three>>::Output>;
+    Equal<Two, <Zero as Clamp<Two, Five>>::Output>;
+    Equal<Four, <Four as Clamp<Two, Five>>::Output>;
+    Equal<Five, <Nine as Clamp<Two, Five>>::Output>;
+
+    // Quotient tests:
+    Equal<Zero, <Two as Quotient<Four>>::Output>;
+    Equal<Two, <Six as Quotient<Three>>::Output>;
+    Equal<Two, <Five as Quotient<Two>>::Output>;
+
+    // Remainder tests:
+    Equal<Two, <Two as Remainder<Four>>::Output>;
+    Equal<Zero, <Six as Remainder<Three>>::Output>;
+    Equal<One, <Five as Remainder<Two>>::Output>;
+
+    // Gcd tests:
+    Equal<Two, <Four as Gcd<Six>>::Output>;
+    Equal<Three, <Zero as Gcd<Three>>::Output>;
+    Equal<One, <Five as Gcd<Three>>::Output>;
+    <Five as Coprime<Six>>::Output;
+    Equal<False, <Four as IsCoprime<Two>>::Output>;
+
+    // Operator tests:
+    Equal<NatValue<Five>, <NatValue<Two> as ::core::ops::Add<NatValue<Three>>>::Output>;
+    Equal<NatValue<Six>, <NatValue<Two> as ::core::ops::Mul<NatValue<Three>>>::Output>;
+    Equal<NatValue<Zero>, <NatValue<Three> as ::core::ops::Sub<NatValue<Three>>>::Output>;
+
+    // Boolean tests:
+    Equal<False, <True as Not>::Output>;
+    Equal<True, <True as And<True>>::Output>;
+    Equal<False, <True as And<False>>::Output>;
+    Equal<True, <False as Or<True>>::Output>;
+    Equal<False, <False as Or<False>>::Output>;
+
+    // Parity tests:
+    Equal<True, IsEven<Zero>>;
+    Equal<False, IsEven<Seven>>;
+    Equal<True, IsEven<Eight>>;
+}
+
+/// One step of the Collatz map: `n / 2` if `n` is even, and `3n + 1` if it's odd.
+pub trait CollatzStep: Nat {
+    type Output: Nat;
+}
+impl<N: Nat> CollatzStep for N where N: Parity, N: CollatzBranch<IsEven<N>> {
+    type Output = <N as CollatzBranch<IsEven<N>>>::Output;
+}
+pub trait CollatzBranch<Even> {
+    type Output: Nat;
+}
+impl<N: Nat> CollatzBranch<True> for N where N: Quotient<Two> {
+    type Output = <N as Quotient<Two>>::Output;
+}
+impl<N: Nat> CollatzBranch<False> for N where N: Product<Three>, <N as Product<Three>>::Output: Nat {
+    type Output = Successor<<N as Product<Three>>::Output>;
+}
+
+/// How many steps of `CollatzStep` it takes to get from `n` down to one.
+///
+/// Every start anyone has tried gets there, but the values climb on the way, and each
+/// step is a whole `Remainder` and `Quotient`, so only small starts fit in the recursion
+/// limit. `CollatzIter` follows the same steps at runtime, and counts the same:
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// use sqrt_irrational::nat::{CollatzIter, CollatzSteps};
+/// fn agree<N: CollatzSteps + Reify>() -> bool where <N as CollatzSteps>::Output: Reify {
+///     CollatzIter::of::<N>().count() as u64 - 1 == <N as CollatzSteps>::Output::OUTPUT
+/// }
+/// assert!(agree::<One>());
+/// assert!(agree::<Three>());
+/// assert!(agree::<Six>());
+/// assert!(agree::<Seven>());
+/// assert!(agree::<Nine>());
+/// ```
+pub trait CollatzSteps: Nat {
+    type Output: Nat;
+}
+impl CollatzSteps for One {
+    type Output = Zero;
+}
+impl<N: Nat> CollatzSteps for Successor<Successor<N>> where
+    Successor<Successor<N>>: CollatzStep,
+    <Successor<Successor<N>> as CollatzStep>::Output: CollatzSteps,
+{
+    type Output = Successor<<<Successor<Successor<N>> as CollatzStep>::Output as CollatzSteps>::Output>;
+}
+
+/// `CollatzStep`, on a `u64`. That's `None` when `3n + 1` doesn't fit.
+pub const fn collatz_step(n: u64) -> Option<u64> {
+    if n % 2 == 0 {
+        return Some(n / 2);
+    }
+    match n.checked_mul(3) {
+        Some(m) => m.checked_add(1),
+        None => None,
+    }
+}
+
+/// The values a start goes through under `collatz_step`, from the start itself down to one.
+///
+/// Zero never gets to one, so it yields nothing. And if the step from some value doesn't fit
+/// in a `u64`, that value is the last it yields, rather than wrapping around.
+///
+/// ```
+/// use sqrt_irrational::nat::CollatzIter;
+/// assert_eq!(CollatzIter::new(6).collect::<Vec<_>>(), vec![6, 3, 10, 5, 16, 8, 4, 2, 1]);
+/// assert_eq!(CollatzIter::new(27).count() - 1, 111);
+/// assert_eq!(CollatzIter::new(27).max(), Some(9232));
+/// assert_eq!(CollatzIter::new(0).count(), 0);
+/// assert_eq!(CollatzIter::new(u64::MAX).collect::<Vec<_>>(), vec![u64::MAX]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CollatzIter { current: u64 }
+impl CollatzIter {
+    pub const fn new(start: u64) -> Self {
+        CollatzIter { current: start }
+    }
+    /// Start from a type-level natural.
+    pub const fn of<N: Reify>() -> Self {
+        Self::new(N::OUTPUT)
+    }
+}
+impl Iterator for CollatzIter {
+    type Item = u64;
+    fn next(&mut self) -> Option<u64> {
+        match self.current {
+            0 => None,
+            1 => {
+                self.current = 0;
+                Some(1)
+            }
+            n => {
+                // Zero never comes up otherwise, so it ends the iterator.
+                self.current = collatz_step(n).unwrap_or(0);
+                Some(n)
+            }
+        }
+    }
+}
+
+proofs! {
+    // Collatz tests:
+    Equal<Four, <Eight as CollatzStep>::Output>;
+    Equal<<Nine as Sum<One>>::Output, <Three as CollatzStep>::Output>;
+    Equal<Zero, <One as CollatzSteps>::Output>;
+    Equal<One, <Two as CollatzSteps>::Output>;
+    Equal<Seven, <Three as CollatzSteps>::Output>;
+    Equal<Eight, <Six as CollatzSteps>::Output>;
+}
+
+// The runtime steps agree with the type-level ones:
+const _: () = assert!(matches!(collatz_step(5), Some(m) if m == <<Five as CollatzStep>::Output as Reify>::OUTPUT));
+const _: () = assert!(matches!(collatz_step(6), Some(m) if m == <<Six as CollatzStep>::Output as Reify>::OUTPUT));
+const _: () = assert!(matches!(collatz_step(9), Some(m) if m == <<Nine as CollatzStep>::Output as Reify>::OUTPUT));
+// And they don't wrap around at the top:
+const _: () = assert!(collatz_step(u64::MAX).is_none());
+
+/// Decidable primality, by trial division.
+pub trait IsPrime {
+    type Output: Bool;
+}
+impl IsPrime for Zero {
+    type Output = False;
+}
+impl IsPrime for One {
+    type Output = False;
+}
+impl<N: Nat> IsPrime for Successor<Successor<N>> where
+    Successor<Successor<N>>: PrimeSearch<Successor<N>>,
+{
+    type Output = <Successor<Successor<N>> as PrimeSearch<Successor<N>>>::Output;
+}
+// Checks that nothing from `Divisor` down to two divides `Self`.
+pub trait PrimeSearch<Divisor> {
+    type Output: Bool;
+}
+impl<A: Nat> PrimeSearch<One> for A {
+    type Output = True;
+}
+impl<A: Nat, D: Nat> PrimeSearch<Successor<Successor<D>>> for A where
+    A: Remainder<Successor<Successor<D>>>,
+    <A as Remainder<Successor<Successor<D>>>>::Output: TypeEq<Zero>,
+    A: PrimeSearchStep<Successor<D>, <<A as Remainder<Successor<Successor<D>>>>::Output as TypeEq<Zero>>::Output>,
+{
+    type Output = <A as PrimeSearchStep<Successor<D>, <<A as Remainder<Successor<Successor<D>>>>::Output as TypeEq<Zero>>::Output>>::Output;
+}
+pub trait PrimeSearchStep<Next, Divisible> {
+    type Output: Bool;
+}
+impl<A: Nat, Next> PrimeSearchStep<Next, True> for A {
+    type Output = False;
+}
+impl<A: Nat, Next> PrimeSearchStep<Next, False> for A where A: PrimeSearch<Next> {
+    type Output = <A as PrimeSearch<Next>>::Output;
+}
+/// Being able to write `P: Prime` is proof that `P` is prime.
+pub trait Prime {}
+impl<P: IsPrime<Output = True>> Prime for P {}
+
+/// Whether `Self` is a nonzero square mod the prime `P`,
+/// found by trying every `x` in `1..P`.
+pub trait IsQuadraticResidue<P: Nat> {
+    type Output: Bool;
+}
+impl<A: Nat, P: Nat + Prime> IsQuadraticResidue<P> for A where
+    P: Difference<One>,
+    A: QuadraticSearch<P, <P as Difference<One>>::Output>,
+{
+    type Output = <A as QuadraticSearch<P, <P as Difference<One>>::Output>>::Output;
+}
+// Checks whether `x² = Self (mod P)` for any `x` from `X` down to one.
+pub trait QuadraticSearch<P: Nat, X> {
+    type Output: Bool;
+}
+impl<A: Nat, P: Nat> QuadraticSearch<P, Zero> for A {
+    type Output = False;
+}
+impl<A: Nat, P: Nat, X: Nat> QuadraticSearch<P, Successor<X>> for A where
+    A: Remainder<P>,
+    Successor<X>: Square,
+    <Successor<X> as Square>::Output: Remainder<P>,
+    <<Successor<X> as Square>::Output as Remainder<P>>::Output: TypeEq<<A as Remainder<P>>::Output>,
+    A: QuadraticSearchStep<P, X, <<<Successor<X> as Square>::Output as Remainder<P>>::Output as TypeEq<<A as Remainder<P>>::Output>>::Output>,
+{
+    type Output = <A as QuadraticSearchStep<P, X, <<<Successor<X> as Square>::Output as Remainder<P>>::Output as TypeEq<<A as Remainder<P>>::Output>>::Output>>::Output;
+}
+pub trait QuadraticSearchStep<P: Nat, Next, Found> {
+    type Output: Bool;
+}
+impl<A: Nat, P: Nat, Next> QuadraticSearchStep<P, Next, True> for A {
+    type Output = True;
+}
+impl<A: Nat, P: Nat, Next> QuadraticSearchStep<P, Next, False> for A where A: QuadraticSearch<P, Next> {
+    type Output = <A as QuadraticSearch<P, Next>>::Output;
+}
+
+proofs! {
+    // Primality tests:
+    Equal<False, <One as IsPrime>::Output>;
+    Equal<True, <Two as IsPrime>::Output>;
+    Equal<True, <Seven as IsPrime>::Output>;
+    Equal<False, <Nine as IsPrime>::Output>;
+
+    // Quadratic residue tests, mod 7 the nonzero squares are 1, 2, and 4:
+    Equal<True, <Two as IsQuadraticResidue<Seven>>::Output>;
+    Equal<True, <Four as IsQuadraticResidue<Seven>>::Output>;
+    Equal<False, <Three as IsQuadraticResidue<Seven>>::Output>;
+    Equal<False, <Zero as IsQuadraticResidue<Seven>>::Output>;
+}
+// `IsQuadraticResidue` is only defined mod a prime, so this fails to compile:
+// proof! { <Two as IsQuadraticResidue<Nine>>::Output }
+
+/// A type-level function on naturals, applied with `<F as NatFn<N>>::Output`.
+pub trait NatFn<N> {
+    type Output;
+}
+/// `n` goes to `n + 1`.
+pub struct SuccessorFn;
+impl<N: Nat> NatFn<N> for SuccessorFn {
+    type Output = Successor<N>;
+}
+pub struct SquareFn;
+impl<N: Square> NatFn<N> for SquareFn {
+    type Output = <N as Square>::Output;
+}
+/// `n` goes to whether it's prime.
+pub struct IsPrimeFn;
+impl<N: IsPrime> NatFn<N> for IsPrimeFn {
+    type Output = <N as IsPrime>::Output;
+}
+/// `n` goes to whether `n² > K`.
+pub struct SquareExceeds<K> { _k: PhantomData<K> }
+impl<N: Square, K> NatFn<N> for SquareExceeds<K> where
+    <N as Square>::Output: Compare<K>,
+    <<N as Square>::Output as Compare<K>>::Output: SelectOrd<False, False, True>,
+{
+    type Output = <<<N as Square>::Output as Compare<K>>::Output as SelectOrd<False, False, True>>::Output;
+}
+/// `n` goes to `Rⁿ`.
+pub struct PowerOf<R> { _r: PhantomData<R> }
+impl<R: Power<N>, N: Nat> NatFn<N> for PowerOf<R> {
+    type Output = <R as Power<N>>::Output;
+}
+
+/// `k` goes to `C(n, k)`, the `k`th entry in row `n` of Pascal's triangle.
+pub struct ChooseFrom<N> { _n: PhantomData<N> }
+impl<N: Choose<K>, K: Nat> NatFn<K> for ChooseFrom<N> {
+    type Output = <N as Choose<K>>::Output;
+}
+
+/// `k` goes to whether it's a divisor of `N` other than one (or zero).
+pub struct DividesAboveOne<N> { _n: PhantomData<N> }
+impl<N> NatFn<Zero> for DividesAboveOne<N> {
+    type Output = False;
+}
+impl<N> NatFn<One> for DividesAboveOne<N> {
+    type Output = False;
+}
+impl<N: Nat, K: Nat> NatFn<Successor<Successor<K>>> for DividesAboveOne<N> where
+    N: Remainder<Successor<Successor<K>>>,
+    <N as Remainder<Successor<Successor<K>>>>::Output: TypeEq<Zero>,
+{
+    type Output = <<N as Remainder<Successor<Successor<K>>>>::Output as TypeEq<Zero>>::Output;
+}
+
+/// Something to do with a natural, once we know which one it is. See `with_nat`.
+pub trait NatContinuation<N: Nat> {
+    type Output;
+    fn call(self) -> Self::Output;
+}
+// Walks down from `Self` looking for the type which reifies to `n`.
+pub trait WithNat<K> {
+    type Output;
+    fn with_nat(n: u64, k: K) -> Option<Self::Output>;
+}
+impl<K: NatContinuation<Zero>> WithNat<K> for Zero {
+    type Output = K::Output;
+    fn with_nat(n: u64, k: K) -> Option<Self::Output> {
+        if n == 0 { Some(k.call()) } else { None }
+    }
+}
+impl<N: Nat + Reify, K> WithNat<K> for Successor<N> where
+    N: WithNat<K>,
+    K: NatContinuation<Successor<N>, Output = <N as WithNat<K>>::Output>,
+{
+    type Output = <N as WithNat<K>>::Output;
+    fn with_nat(n: u64, k: K) -> Option<Self::Output> {
+        if n == Self::OUTPUT { Some(k.call()) } else { N::with_nat(n, k) }
+    }
+}
+/// Calls `k` with the type-level natural for `n`, if `n` is at most `Bound`.
+///
+/// Types can't depend on runtime values, so every natural up to `Bound` gets
+/// its own copy of the continuation, and we pick the right one at runtime.
+pub fn with_nat<Bound: WithNat<K>, K>(n: u64, k: K) -> Option<Bound::Output> {
+    Bound::with_nat(n, k)
+}
+
+/// A natural built at runtime, one successor at a time, for getting from a loop counter
+/// to a type-level natural that proofs can be applied to.
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// use sqrt_irrational::nat::{NatBuilder, NatContinuation};
+///
+/// // Ask `Parity` about whichever natural the builder got to.
+/// struct IsItEven;
+/// impl<N: Nat + Parity> NatContinuation<N> for IsItEven {
+///     type Output = bool;
+///     fn call(self) -> bool { <IsEven<N> as Bool>::OUTPUT }
+/// }
+///
+/// let three = NatBuilder::new().succ().succ().succ();
+/// assert_eq!(three.build(), 3);
+/// assert_eq!(three.with_nat::<Nine, _>(IsItEven), Some(false));
+/// assert_eq!(three.succ().with_nat::<Nine, _>(IsItEven), Some(true));
+/// assert_eq!(NatBuilder::new().with_nat::<Nine, _>(IsItEven), Some(true));
+///
+/// let mut counter = NatBuilder::new();
+/// for _ in 0..7 {
+///     counter = counter.succ();
+/// }
+/// assert_eq!(counter.with_nat::<Nine, _>(IsItEven), Some(false));
+/// // Past the bound, there's no type to hand over.
+/// assert_eq!(counter.succ().succ().succ().with_nat::<Nine, _>(IsItEven), None);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NatBuilder { count: u64 }
+impl NatBuilder {
+    /// Zero.
+    pub const fn new() -> Self {
+        NatBuilder { count: 0 }
+    }
+    /// One more than `self`.
+    pub const fn succ(self) -> Self {
+        NatBuilder { count: self.count + 1 }
+    }
+    /// The number built so far.
+    pub const fn build(self) -> u64 {
+        self.count
+    }
+    /// Calls `k` with the type-level natural for the number built so far, if that's at
+    /// most `Bound`. See `with_nat`.
+    pub fn with_nat<Bound: WithNat<K>, K>(self, k: K) -> Option<Bound::Output> {
+        with_nat::<Bound, K>(self.count, k)
+    }
+}
+
+// Reifies the result of applying `F`.
+pub struct ApplyFn<F> { _f: PhantomData<F> }
+impl<F: NatFn<N>, N: Nat> NatContinuation<N> for ApplyFn<F> where <F as NatFn<N>>::Output: Reify {
+    type Output = u64;
+    fn call(self) -> u64 { <<F as NatFn<N>>::Output as Reify>::OUTPUT }
+}
+/// Materializes `F` as a runtime table over `0..n`.
+///
+/// Panics if `n - 1` is bigger than `Bound`.
+#[cfg(feature = "alloc")]
+pub fn tabulate<F, Bound: WithNat<ApplyFn<F>, Output = u64>>(n: usize) -> Vec<u64> {
+    (0..n as u64)
+        .map(|i| with_nat::<Bound, _>(i, ApplyFn { _f: PhantomData }).expect("index past the table's bound"))
+        .collect()
+}
+
+/// A type-level function of two naturals, applied with `<F as NatBinFn<A, B>>::Output`.
+pub trait NatBinFn<A, B> {
+    type Output;
+}
+pub struct SumFn;
+impl<A: Sum<B>, B: Nat> NatBinFn<A, B> for SumFn {
+    type Output = <A as Sum<B>>::Output;
+}
+pub struct DifferenceFn;
+impl<A: Difference<B>, B: Nat> NatBinFn<A, B> for DifferenceFn {
+    type Output = <A as Difference<B>>::Output;
+}
+pub struct ProductFn;
+impl<A: Product<B>, B: Nat> NatBinFn<A, B> for ProductFn {
+    type Output = <A as Product<B>>::Output;
+}
+/// `SumFn`, under the name folds usually give it.
+pub type AddFn = SumFn;
+/// `ProductFn`, under the name folds usually give it.
+pub type MulFn = ProductFn;
+pub struct QuotientFn;
+impl<A: Quotient<B>, B: Nat> NatBinFn<A, B> for QuotientFn {
+    type Output = <A as Quotient<B>>::Output;
+}
+pub struct GcdFn;
+impl<A: Gcd<B>, B: Nat> NatBinFn<A, B> for GcdFn {
+    type Output = <A as Gcd<B>>::Output;
+}
+pub struct MinFn;
+impl<A: Min<B>, B: Nat> NatBinFn<A, B> for MinFn {
+    type Output = <A as Min<B>>::Output;
+}
+pub struct MaxFn;
+impl<A: Max<B>, B: Nat> NatBinFn<A, B> for MaxFn {
+    type Output = <A as Max<B>>::Output;
+}
+
+/// `<Op as NatBinFn<A, Self>>::Output`, for when `Self` isn't known yet.
+pub trait AppliedTo<Op, A> {
+    type Output;
+}
+impl<B, Op: NatBinFn<A, B>, A> AppliedTo<Op, A> for B {
+    type Output = <Op as NatBinFn<A, B>>::Output;
+}
+
+/// Checks that `Op` agrees with `expected` at `A` and `B`, for cross-checking the
+/// type-level arithmetic against the ordinary kind in tests. Panics if it doesn't.
+/// Hidden from the docs and kept out of the prelude, since it's test scaffolding.
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// use sqrt_irrational::nat::check_binop;
+/// check_binop::<Seven, Three, QuotientFn>(|a, b| a / b);
+/// ```
+///
+/// ```should_panic
+/// use sqrt_irrational::prelude::*;
+/// use sqrt_irrational::nat::check_binop;
+/// check_binop::<Two, Three, SumFn>(|a, b| a * b);
+/// ```
+#[doc(hidden)]
+pub fn check_binop<A: Reify, B: Reify, Op: NatBinFn<A, B>>(expected: impl Fn(u64, u64) -> u64) where
+    <Op as NatBinFn<A, B>>::Output: Reify,
+{
+    let (a, b) = (A::OUTPUT, B::OUTPUT);
+    assert_eq!(<<Op as NatBinFn<A, B>>::Output as Reify>::OUTPUT, expected(a, b), "at a = {a}, b = {b}");
+}
+
+// Reifies the result of applying `Op` to `A` and whichever `B` we're called with.
+#[doc(hidden)]
+pub struct ApplyBinFn<Op, A> { _f: PhantomData<(Op, A)> }
+impl<Op: NatBinFn<A, B>, A: Nat, B: Nat> NatContinuation<B> for ApplyBinFn<Op, A> where
+    <Op as NatBinFn<A, B>>::Output: Reify,
+{
+    type Output = u64;
+    fn call(self) -> u64 { <<Op as NatBinFn<A, B>>::Output as Reify>::OUTPUT }
+}
+// Once `A` is known, goes looking for the `B` that reifies to `b`.
+#[doc(hidden)]
+pub struct ApplyBinFnTo<Op, Bound> { b: u64, _f: PhantomData<(Op, Bound)> }
+impl<Op, Bound, A: Nat> NatContinuation<A> for ApplyBinFnTo<Op, Bound> where
+    Bound: WithNat<ApplyBinFn<Op, A>, Output = u64>,
+{
+    type Output = Option<u64>;
+    fn call(self) -> Option<u64> { with_nat::<Bound, _>(self.b, ApplyBinFn { _f: PhantomData }) }
+}
+/// `check_binop` at every `a` and `b` up to `Bound`.
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// use sqrt_irrational::nat::check_binop_grid;
+/// check_binop_grid::<SumFn, Five>(|a, b| a + b);
+/// check_binop_grid::<ProductFn, Five>(|a, b| a * b);
+/// fn gcd(a: u64, b: u64) -> u64 { if b == 0 { a } else { gcd(b, a % b) } }
+/// check_binop_grid::<GcdFn, Four>(gcd);
+/// ```
+#[doc(hidden)]
+pub fn check_binop_grid<Op, Bound>(expected: impl Fn(u64, u64) -> u64) where
+    Bound: Reify + WithNat<ApplyBinFnTo<Op, Bound>, Output = Option<u64>>,
+{
+    for a in 0..=Bound::OUTPUT {
+        for b in 0..=Bound::OUTPUT {
+            let output = with_nat::<Bound, _>(a, ApplyBinFnTo { b, _f: PhantomData }).flatten();
+            assert_eq!(output, Some(expected(a, b)), "at a = {a}, b = {b}");
+        }
+    }
+}
+
+proofs! {
+    Equal<Nine, <SquareFn as NatFn<Three>>::Output>;
+    Equal<Zero, <SquareFn as NatFn<Zero>>::Output>;
+}
+
+// Let's start by just labeling some numbers. That's easy.
+proofs! {
+    pub type One = Successor<Zero>;
+    pub type Two = Successor<One>;
+    pub type Three = Successor<Two>;
+    pub type Four = Successor<Three>;
+    // Mostly writing this here just to ensure my macro works for
+    // interleaving named and anonymous proofs.
+    Equal<<Two as Sum<Two>>::Output, Four>;
+    pub type Five = Successor<Four>;
+    Equal<<Two as Sum<Three>>::Output, Five>;
+    pub type Six = Successor<Five>;
+    pub type Seven = Successor<Six>;
+    pub type Eight = Successor<Seven>;
+    pub type Nine = Successor<Eight>;
+}
@@ -0,0 +1,3027 @@
+//! Equality of types, the lemmas, and finally the proof itself.
+
+use ::core::marker::PhantomData;
+
+use crate::binary::*;
+use crate::int::*;
+use crate::list::*;
+use crate::nat::*;
+use crate::ratio::*;
+
+/// Reflexive equality.
+pub trait Congruent<A> {}
+impl<A> Congruent<A> for A {}
+// Being able to write this type is proof that the two argument types are equal.
+pub struct Equal<A, B: Congruent<A>> { _a: PhantomData<A>, _b: PhantomData<B> }
+
+// ## Lemmas
+
+/// `gcd(a, b)` divides both `a` and `b`.
+///
+/// The proof follows Euclid's algorithm, building the cofactors `Left = a / gcd(a, b)`
+/// and `Right = b / gcd(a, b)` as it goes. That way the impls can be
+/// checked for all naturals at once, by induction:
+/// - `gcd(a, 0) = a`, and `a = a·1` and `0 = a·0`.
+/// - `gcd(a, b) = gcd(b, r)` where `a = qb + r`. By induction `g = gcd(b, r)` has
+///   `b = g·k₁` and `r = g·k₂`, so `a = q(g·k₁) + g·k₂ = g(q·k₁ + k₂)`.
+///
+/// `a = qb + r` is `DivisionIdentity`, and `a·1 = a` is `ZeroSum`. Moving `g` out to the front
+/// is `ProductCommutes`, `ProductAssociative` and `ProductDistributes`. They're all proved
+/// below, so this holds for every `a` and `b` outright.
+pub trait GcdDivides<B> {
+    type Left;
+    type Right;
+    type Proof;
+}
+impl<A: Nat> GcdDivides<Zero> for A where
+    // `a·1 = a·0 + a = 0 + a = a`, by `ZeroSum`.
+    A: ZeroSum,
+    Zero: Sum<A, Output = A>,
+{
+    type Left = One;
+    type Right = Zero;
+    type Proof = (
+        Equal<A, <<A as Gcd<Zero>>::Output as Product<One>>::Output>,
+        Equal<Zero, <<A as Gcd<Zero>>::Output as Product<Zero>>::Output>,
+    );
+}
+// Names for the pieces of one step of Euclid's algorithm on `a` and `b`, where `a = qb + r`,
+// `g = gcd(b, r)`, and the induction hypothesis gives `b = g·k₁` and `r = g·k₂`.
+// These have to be spelled out as projections rather than fresh impl parameters, or rustc
+// goes looking for `g·?` before it knows what `?` is, tries every natural, and overflows.
+type EuclidQ<A, B> = <A as Quotient<B>>::Output;
+type EuclidR<A, B> = <A as Remainder<B>>::Output;
+type EuclidG<A, B> = <B as Gcd<EuclidR<A, B>>>::Output;
+type EuclidK1<A, B> = <B as GcdDivides<EuclidR<A, B>>>::Left;
+type EuclidK2<A, B> = <B as GcdDivides<EuclidR<A, B>>>::Right;
+type EuclidQK1<A, B> = <EuclidQ<A, B> as Product<EuclidK1<A, B>>>::Output;
+impl<A: Nat, B: Nat> GcdDivides<Successor<B>> for A where
+    // Euclid's step, `gcd(a, b) = gcd(b, r)`.
+    A: Remainder<Successor<B>> + Quotient<Successor<B>>,
+    Successor<B>: Gcd<EuclidR<A, Successor<B>>>,
+    // Induction hypothesis: `b = g·k₁` and `r = g·k₂`.
+    Successor<B>: GcdDivides<EuclidR<A, Successor<B>>>,
+    EuclidK1<A, Successor<B>>: Nat,
+    EuclidK2<A, Successor<B>>: Nat,
+    EuclidG<A, Successor<B>>: Product<EuclidK1<A, Successor<B>>, Output = Successor<B>>
+        + Product<EuclidK2<A, Successor<B>>, Output = EuclidR<A, Successor<B>>>,
+    // Division with remainder: `a = qb + r`, by `DivisionIdentity`.
+    A: DivisionIdentity<Successor<B>>,
+    EuclidQ<A, Successor<B>>: Product<Successor<B>>,
+    <EuclidQ<A, Successor<B>> as Product<Successor<B>>>::Output: Sum<EuclidR<A, Successor<B>>, Output = A>,
+    // The new cofactor, `q·k₁ + k₂`.
+    EuclidQ<A, Successor<B>>: Product<EuclidK1<A, Successor<B>>>,
+    EuclidQK1<A, Successor<B>>: Nat + Sum<EuclidK2<A, Successor<B>>>,
+    <EuclidQK1<A, Successor<B>> as Sum<EuclidK2<A, Successor<B>>>>::Output: Nat,
+    // `g(q·k₁) = (q·k₁)g = q(k₁g) = q(g·k₁)`, by `ProductCommutes`, `ProductAssociative` and
+    // `ProductCommutes` again.
+    EuclidQK1<A, Successor<B>>: ProductCommutes<EuclidG<A, Successor<B>>>,
+    EuclidG<A, Successor<B>>: Product<
+        EuclidQK1<A, Successor<B>>,
+        Output = MulMN<EuclidQK1<A, Successor<B>>, EuclidG<A, Successor<B>>>,
+    >,
+    EuclidG<A, Successor<B>>: ProductAssociative<EuclidQ<A, Successor<B>>, EuclidK1<A, Successor<B>>>,
+    EuclidQK1<A, Successor<B>>: Product<
+        EuclidG<A, Successor<B>>,
+        Output = MulMN<EuclidQ<A, Successor<B>>, MulMN<EuclidK1<A, Successor<B>>, EuclidG<A, Successor<B>>>>,
+    >,
+    EuclidG<A, Successor<B>>: ProductCommutes<EuclidK1<A, Successor<B>>>,
+    EuclidK1<A, Successor<B>>: Product<
+        EuclidG<A, Successor<B>>,
+        Output = MulMN<EuclidG<A, Successor<B>>, EuclidK1<A, Successor<B>>>,
+    >,
+    // `g(q·k₁ + k₂) = g(q·k₁) + g·k₂`, by `ProductDistributes`.
+    EuclidK2<A, Successor<B>>: ProductDistributes<EuclidG<A, Successor<B>>, EuclidQK1<A, Successor<B>>>,
+    EuclidG<A, Successor<B>>: Product<
+        <EuclidQK1<A, Successor<B>> as Sum<EuclidK2<A, Successor<B>>>>::Output,
+        Output = <MulMN<EuclidG<A, Successor<B>>, EuclidQK1<A, Successor<B>>>
+            as Sum<MulMN<EuclidG<A, Successor<B>>, EuclidK2<A, Successor<B>>>>>::Output,
+    >,
+{
+    type Left = <EuclidQK1<A, Successor<B>> as Sum<EuclidK2<A, Successor<B>>>>::Output;
+    type Right = EuclidK1<A, Successor<B>>;
+    type Proof = (
+        Equal<A, <<A as Gcd<Successor<B>>>::Output as Product<
+            <EuclidQK1<A, Successor<B>> as Sum<EuclidK2<A, Successor<B>>>>::Output
+        >>::Output>,
+        Equal<Successor<B>, <<A as Gcd<Successor<B>>>::Output as Product<EuclidK1<A, Successor<B>>>>::Output>,
+    );
+}
+
+proofs! {
+    // Divisibility tests:
+    <Three as Divides<Six>>::Output;
+    <Four as Divides<Zero>>::Output;
+    <Zero as Divides<Zero>>::Output;
+
+    // `gcd(a, b)` divides `a` and `b`:
+    <<Four as Gcd<Six>>::Output as Divides<Four>>::Output;
+    <<Four as Gcd<Six>>::Output as Divides<Six>>::Output;
+    <<Nine as Gcd<Six>>::Output as Divides<Nine>>::Output;
+    <Four as GcdDivides<Six>>::Proof;
+    <Nine as GcdDivides<Six>>::Proof;
+    <Five as GcdDivides<Three>>::Proof;
+    <Zero as GcdDivides<Seven>>::Proof;
+    Equal<Three, <Nine as GcdDivides<Six>>::Left>;
+    Equal<Two, <Nine as GcdDivides<Six>>::Right>;
+}
+// But not everything divides everything:
+// proof! { <Four as Divides<Six>>::Output }
+
+/// `a | b` and `b | c` give `a | c`, with the cofactor to show for it:
+/// if `b = ak` and `c = bl`, then `c = (ak)l = a(kl)`.
+///
+/// The cofactors come from `Quotient`, and the steps are:
+/// - `k = b / a` and `l = c / b` undo multiplication, since the divisions are exact.
+/// - `(ak)l = a(kl)`, by `ProductAssociative`.
+///
+/// `ProductAssociative` is proved below. The first step isn't proved for all naturals here, so
+/// it's left to each concrete `a`, `b` and `c` the `Proof` is used at. When `b = 0`, `c` has to
+/// be too, and `a·0 = 0` needs nothing at all.
+///
+/// There's no `SqrtPrimeIrrational` here to refactor onto it: the irrationality proofs in this
+/// crate are all about `√2`, and argue by parity.
+pub trait DividesTransitive<B, C> {
+    type Cofactor;
+    type Proof;
+}
+impl<A: Nat> DividesTransitive<Zero, Zero> for A {
+    type Cofactor = Zero;
+    type Proof = Equal<Zero, <A as Product<Zero>>::Output>;
+}
+type DivK<A, B> = <B as Quotient<A>>::Output;
+type DivKL<A, B, C> = <DivK<A, B> as Product<DivK<B, C>>>::Output;
+impl<A: Nat, B: Nat, C: Nat> DividesTransitive<Successor<B>, C> for Successor<A> where
+    // Hypotheses: `a | b` and `b | c`.
+    Successor<A>: Divides<Successor<B>>,
+    Successor<B>: Divides<C>,
+    // The divisions are exact: `b = ak` and `c = bl`.
+    Successor<B>: Quotient<Successor<A>>,
+    C: Quotient<Successor<B>>,
+    Successor<A>: Product<DivK<Successor<A>, Successor<B>>, Output = Successor<B>>,
+    Successor<B>: Product<DivK<Successor<B>, C>, Output = C>,
+    // `a(kl) = (ak)l`, by `ProductAssociative`.
+    DivK<Successor<A>, Successor<B>>: Product<DivK<Successor<B>, C>>,
+    DivK<Successor<B>, C>: ProductAssociative<Successor<A>, DivK<Successor<A>, Successor<B>>>,
+    Successor<A>: Product<
+        DivKL<Successor<A>, Successor<B>, C>,
+        Output = <<Successor<A> as Product<DivK<Successor<A>, Successor<B>>>>::Output as Product<DivK<Successor<B>, C>>>::Output,
+    >,
+{
+    type Cofactor = DivKL<Successor<A>, Successor<B>, C>;
+    type Proof = Equal<C, <Successor<A> as Product<DivKL<Successor<A>, Successor<B>, C>>>::Output>;
+}
+
+proofs! {
+    <Two as DividesTransitive<Four, Eight>>::Proof;
+    Equal<Four, <Two as DividesTransitive<Four, Eight>>::Cofactor>;
+    <One as DividesTransitive<Three, Six>>::Proof;
+    <Three as DividesTransitive<Three, Nine>>::Proof;
+    <Five as DividesTransitive<Zero, Zero>>::Proof;
+}
+// It takes both hypotheses, `4` doesn't divide `6`:
+// proof! { <Two as DividesTransitive<Four, Six>>::Proof }
+
+// ### The gcd is symmetric.
+
+type GcdMN<A, B> = <A as Gcd<B>>::Output;
+
+/// `gcd(a, b) = gcd(b, a)`.
+///
+/// Euclid's algorithm swaps its arguments when the first is smaller, so by comparing `a` with `b`:
+/// - `a < b`: `gcd(a, b) = gcd(b, a mod b) = gcd(b, a)`, since `a mod b = a`.
+/// - `a = b`: there's nothing to swap.
+/// - `a > b`: the same step the other way round, `gcd(b, a) = gcd(a, b mod a) = gcd(a, b)`.
+///
+/// rustc checks each case for all `a` and `b` at once. That `a > b` means `b < a` is checked at
+/// each concrete pair.
+pub trait GcdCommutes<B: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat, B: Nat> GcdCommutes<B> for A where
+    A: Compare<B>,
+    A: GcdCommutesStep<B, <A as Compare<B>>::Output>,
+{
+    type Proof = <A as GcdCommutesStep<B, <A as Compare<B>>::Output>>::Proof;
+}
+// One impl per way `a` can compare with `b`.
+pub trait GcdCommutesStep<B: Nat, Ordering>: Nat {
+    type Proof;
+}
+impl<A: Nat, B: Nat> GcdCommutesStep<Successor<B>, Less> for A where
+    A: Compare<Successor<B>, Output = Less>,
+    Successor<B>: Gcd<A>,
+{
+    type Proof = Equal<GcdMN<A, Successor<B>>, GcdMN<Successor<B>, A>>;
+}
+// Comparing equal means `a` is `b`, so this only needs an impl for `b` itself.
+impl<B: Nat> GcdCommutesStep<B, EqualOrd> for B where
+    B: Gcd<B>,
+{
+    type Proof = Equal<GcdMN<B, B>, GcdMN<B, B>>;
+}
+impl<A: Nat, B: Nat> GcdCommutesStep<B, Greater> for Successor<A> where
+    Successor<A>: Compare<B, Output = Greater>,
+    // `a > b` is `b < a`.
+    B: Compare<Successor<A>, Output = Less>,
+    Successor<A>: Gcd<B>,
+{
+    type Proof = Equal<GcdMN<B, Successor<A>>, GcdMN<Successor<A>, B>>;
+}
+
+/// A ratio in lowest terms stays that way when it's turned upside down: if `gcd(n, d) = 1`, then
+/// `Reciprocal` gives `d/n`, and `gcd(d, n) = gcd(n, d) = 1` by `GcdCommutes`.
+///
+/// So `Reciprocal` doesn't need to simplify anything to keep a ratio canonical.
+pub trait ReciprocalCoprime {
+    type Proof;
+}
+impl<N: Nat, D: Nat> ReciprocalCoprime for Ratio<Successor<N>, D> where
+    Ratio<Successor<N>, D>: ValidRatio,
+    // Hypothesis: `gcd(n, d) = 1`.
+    Successor<N>: Gcd<D, Output = One>,
+    // `gcd(d, n) = gcd(n, d)`.
+    D: GcdCommutes<Successor<N>>,
+    D: Gcd<Successor<N>, Output = GcdMN<Successor<N>, D>>,
+{
+    type Proof = (
+        Equal<Ratio<D, Successor<N>>, <Ratio<Successor<N>, D> as Reciprocal>::Output>,
+        <D as Coprime<Successor<N>>>::Output,
+    );
+}
+
+proofs! {
+    <Four as GcdCommutes<Six>>::Proof;
+    <Six as GcdCommutes<Four>>::Proof;
+    <Five as GcdCommutes<Five>>::Proof;
+    <Zero as GcdCommutes<Seven>>::Proof;
+    <Seven as GcdCommutes<Zero>>::Proof;
+    <Nine as GcdCommutes<Two>>::Proof;
+    Equal<<Eight as Gcd<Six>>::Output, <Six as Gcd<Eight>>::Output>;
+    <Ratio<Two, Three> as ReciprocalCoprime>::Proof;
+    <Ratio<Nine, Four> as ReciprocalCoprime>::Proof;
+    <Ratio<One, Seven> as ReciprocalCoprime>::Proof;
+}
+// Only for ratios in lowest terms, and not for zero, which has no reciprocal:
+// proof! { <Ratio<Two, Four> as ReciprocalCoprime>::Proof }
+// proof! { <Ratio<Zero, One> as ReciprocalCoprime>::Proof }
+
+// ### Well-ordering, up to a bound.
+
+/// If `F` holds at `N`, there's a least `M ≤ N` where it holds.
+///
+/// `Output` is that `M`, found by searching up from `Zero`. The `Proof` is the search's own
+/// record: `Equal<True, F(M)>`, and a list of `Equal<False, F(k)>` for every `k < M`.
+/// `N` is what keeps the search finite. It can't get past `N`, because `F(N)` holds.
+pub trait LeastSuchThat<N>: NatFn<N> {
+    type Output: Nat;
+    type Proof;
+}
+impl<F, N: Nat> LeastSuchThat<N> for F where
+    // Hypothesis: `F(N)` holds.
+    F: NatFn<N, Output = True>,
+    F: LeastFrom<N, Zero>,
+{
+    type Output = <F as LeastFrom<N, Zero>>::Output;
+    type Proof = (<F as LeastFrom<N, Zero>>::Hit, <F as LeastFrom<N, Zero>>::Misses);
+}
+// Searches up from `K`, keeping track of where `F` didn't hold along the way.
+pub trait LeastFrom<N, K> {
+    type Output: Nat;
+    type Hit;
+    type Misses;
+}
+impl<F, N, K> LeastFrom<N, K> for F where
+    F: NatFn<K>,
+    F: LeastStep<N, K, <F as NatFn<K>>::Output>,
+{
+    type Output = <F as LeastStep<N, K, <F as NatFn<K>>::Output>>::Output;
+    type Hit = <F as LeastStep<N, K, <F as NatFn<K>>::Output>>::Hit;
+    type Misses = <F as LeastStep<N, K, <F as NatFn<K>>::Output>>::Misses;
+}
+pub trait LeastStep<N, K, Holds> {
+    type Output: Nat;
+    type Hit;
+    type Misses;
+}
+impl<F, N: Nat, K: Nat> LeastStep<N, K, True> for F where
+    F: NatFn<K, Output = True>,
+    K: LessThanOrEqual<N>,
+{
+    type Output = K;
+    type Hit = Equal<True, <F as NatFn<K>>::Output>;
+    type Misses = TNil;
+}
+impl<F, N: Nat, K: Nat> LeastStep<N, K, False> for F where
+    F: NatFn<K, Output = False>,
+    // There's still room below the bound.
+    K: LessThan<N>,
+    F: LeastFrom<N, Successor<K>>,
+{
+    type Output = <F as LeastFrom<N, Successor<K>>>::Output;
+    type Hit = <F as LeastFrom<N, Successor<K>>>::Hit;
+    type Misses = TCons<Equal<False, <F as NatFn<K>>::Output>, <F as LeastFrom<N, Successor<K>>>::Misses>;
+}
+
+proofs! {
+    // The least prime is `2`, whichever prime you start from.
+    Equal<Two, <IsPrimeFn as LeastSuchThat<Seven>>::Output>;
+    Equal<Two, <IsPrimeFn as LeastSuchThat<Two>>::Output>;
+    <IsPrimeFn as LeastSuchThat<Seven>>::Proof;
+    Equal<(Equal<True, True>, TCons<Equal<False, False>, TCons<Equal<False, False>, TNil>>),
+          <IsPrimeFn as LeastSuchThat<Five>>::Proof>;
+    // The least `n` with `n² > 9` is `4`.
+    Equal<Four, <SquareExceeds<Nine> as LeastSuchThat<Six>>::Output>;
+    <SquareExceeds<Nine> as LeastSuchThat<Six>>::Proof;
+}
+// `F` has to hold at the bound, or there's nothing to find:
+// proof! { <IsPrimeFn as LeastSuchThat<Nine>>::Output }
+// proof! { <SquareExceeds<Nine> as LeastSuchThat<Three>>::Output }
+
+// ### Every natural above one has a prime divisor.
+
+/// The least divisor of `n` above one, which is prime.
+///
+/// There's always one to find, because `n` divides itself, so the search is `LeastSuchThat`
+/// bounded by `n`. Its `Proof` is the search's record, that no `k` from `2` up to the divisor
+/// `p` divides `n`, then that `p` does, and that it's prime. That follows from the record:
+/// anything above one dividing `p` would divide `n` too, and be a smaller divisor. Rustc
+/// doesn't follow that argument, so `p: Prime` is checked by trial division at each `n`.
+pub trait LeastDivisorAboveOne: Nat + GreaterThan<One> {
+    type Output: Nat + Prime;
+    type Proof;
+}
+type LeastDivisor<N> = <DividesAboveOne<N> as LeastSuchThat<N>>::Output;
+impl<N: Nat> LeastDivisorAboveOne for N where
+    // Hypothesis: `n > 1`.
+    N: GreaterThan<One>,
+    DividesAboveOne<N>: LeastSuchThat<N>,
+    LeastDivisor<N>: IsPrime<Output = True> + Divides<N>,
+{
+    type Output = LeastDivisor<N>;
+    type Proof = (
+        <DividesAboveOne<N> as LeastSuchThat<N>>::Proof,
+        <LeastDivisor<N> as Divides<N>>::Output,
+        Equal<True, <LeastDivisor<N> as IsPrime>::Output>,
+    );
+}
+
+proofs! {
+    // A prime is its own least divisor.
+    Equal<Seven, <Seven as LeastDivisorAboveOne>::Output>;
+    <Seven as LeastDivisorAboveOne>::Proof;
+    Equal<Two, <Two as LeastDivisorAboveOne>::Output>;
+    Equal<Two, <Eight as LeastDivisorAboveOne>::Output>;
+    Equal<Three, <Nine as LeastDivisorAboveOne>::Output>;
+    <Nine as LeastDivisorAboveOne>::Proof;
+    // Nothing from `2` to `4` divides `25`.
+    Equal<Five, <<Five as Square>::Output as LeastDivisorAboveOne>::Output>;
+    Equal<(Equal<True, True>, TCons<Equal<False, False>, TCons<Equal<False, False>, TNil>>),
+          <DividesAboveOne<Six> as LeastSuchThat<Six>>::Proof>;
+}
+// One has no divisor above one:
+// proof! { <One as LeastDivisorAboveOne>::Output }
+
+// By value, the same way.
+const fn least_divisor_above_one(n: u64) -> u64 {
+    let mut k = 2;
+    while n % k != 0 {
+        k += 1;
+    }
+    k
+}
+const _: () = assert!(least_divisor_above_one(7) == <<Seven as LeastDivisorAboveOne>::Output as Reify>::OUTPUT);
+const _: () = assert!(least_divisor_above_one(9) == <<Nine as LeastDivisorAboveOne>::Output as Reify>::OUTPUT);
+const _: () = assert!(least_divisor_above_one(25) == 5 && least_divisor_above_one(91) == 7);
+
+// ### The sum of the first `n` odd numbers is `n²`.
+
+// `1 + 3 + 5 + ... + (2n - 1) = n²`, as an actual list of odd numbers.
+proofs! {
+    Equal<<Zero as Square>::Output, <<RangeList<Zero, Zero> as MapList<OddFn>>::Output as SumList>::Output>;
+    Equal<<One as Square>::Output, <<RangeList<Zero, One> as MapList<OddFn>>::Output as SumList>::Output>;
+    Equal<<Two as Square>::Output, <<RangeList<Zero, Two> as MapList<OddFn>>::Output as SumList>::Output>;
+    Equal<<Three as Square>::Output, <<RangeList<Zero, Three> as MapList<OddFn>>::Output as SumList>::Output>;
+    Equal<<Four as Square>::Output, <<RangeList<Zero, Four> as MapList<OddFn>>::Output as SumList>::Output>;
+}
+
+/// `(a + 1) + n = (a + n) + 1`. `Sum` recurses on its right argument, so this takes induction.
+pub trait SuccessorSum<A: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat> SuccessorSum<A> for Zero {
+    type Proof = Equal<Successor<<A as Sum<Zero>>::Output>, <Successor<A> as Sum<Zero>>::Output>;
+}
+impl<A: Nat, N: Nat> SuccessorSum<A> for Successor<N> where
+    // Induction hypothesis, at `a + 1`: `(a + 2) + n = ((a + 1) + n) + 1`.
+    N: SuccessorSum<Successor<A>>,
+    Successor<A>: Sum<N>,
+    <Successor<A> as Sum<N>>::Output: Nat,
+    Successor<Successor<A>>: Sum<N, Output = Successor<<Successor<A> as Sum<N>>::Output>>,
+{
+    type Proof = Equal<Successor<<A as Sum<Successor<N>>>::Output>, <Successor<A> as Sum<Successor<N>>>::Output>;
+}
+
+/// The sum of the first `n` odd numbers, added up in the order induction wants them.
+pub trait OddSum {
+    type Output;
+}
+impl OddSum for Zero {
+    type Output = Zero;
+}
+impl<N: Nat + OddSum> OddSum for Successor<N> where
+    OddFn: NatFn<N>,
+    <OddFn as NatFn<N>>::Output: Nat,
+    <N as OddSum>::Output: Sum<<OddFn as NatFn<N>>::Output>,
+{
+    type Output = <<N as OddSum>::Output as Sum<<OddFn as NatFn<N>>::Output>>::Output;
+}
+
+/// `1 + 3 + ... + (2n - 1) = n²` for every `n`, by induction:
+/// - Zero odd numbers add up to `0 = 0²`.
+/// - `(n + 1)² = (n + 1)n + (n + 1) = (n² + n) + (n + 1) = n² + (2n + 1)`,
+///   which by induction is the sum of the first `n` odd numbers plus the next one.
+///
+/// The facts the step uses are in its `where` clause, and rustc checks they give the
+/// conclusion. `SuccessorSum` is proved above, and `SuccessorProduct` (for `(n + 1)n = n² + n`)
+/// and `SumAssociative` below, so this holds for every `n` outright.
+pub trait OddSumIsSquare: Nat {
+    type Proof;
+}
+impl OddSumIsSquare for Zero {
+    type Proof = Equal<<Zero as OddSum>::Output, <Zero as Square>::Output>;
+}
+impl<N: Nat> OddSumIsSquare for Successor<N> where
+    N: OddSum + Sum<N> + Product<N>,
+    <N as Sum<N>>::Output: Nat,
+    <N as Product<N>>::Output: Nat + Sum<N>,
+    <<N as Product<N>>::Output as Sum<N>>::Output: Nat,
+    // Induction hypothesis: the first `n` odd numbers add up to `n²`.
+    N: OddSumIsSquare + OddSum<Output = <N as Product<N>>::Output>,
+    // `(n + 1)n = n² + n`, which is `SuccessorProduct`.
+    N: SuccessorProduct<N>,
+    Successor<N>: Product<N, Output = <<N as Product<N>>::Output as Sum<N>>::Output>,
+    // `SumAssociative`: `(n² + n) + (n + 1) = n² + (n + (n + 1))`.
+    Successor<N>: SumAssociative<<N as Product<N>>::Output, N>,
+    <<N as Product<N>>::Output as Sum<N>>::Output: Sum<
+        Successor<N>,
+        Output = <<N as Product<N>>::Output as Sum<<N as Sum<Successor<N>>>::Output>>::Output,
+    >,
+    // `n + (n + 1) = (n + n) + 1`, which is `SuccessorSum`.
+    N: SuccessorSum<N> + Sum<Successor<N>, Output = Successor<<N as Sum<N>>::Output>>,
+    <N as Product<N>>::Output: Sum<Successor<<N as Sum<N>>::Output>>,
+{
+    type Proof = Equal<<Successor<N> as OddSum>::Output, <Successor<N> as Square>::Output>;
+}
+
+proofs! {
+    <Two as SuccessorSum<Three>>::Proof;
+    <Zero as SuccessorSum<Four>>::Proof;
+    Equal<Nine, <Three as OddSum>::Output>;
+    <Zero as OddSumIsSquare>::Proof;
+    <One as OddSumIsSquare>::Proof;
+    <Three as OddSumIsSquare>::Proof;
+    <Five as OddSumIsSquare>::Proof;
+}
+
+// ### Zero and one are identities.
+
+/// `n + 0 = n`, which is how `Sum` is defined.
+pub trait SumZero: Nat {
+    type Proof;
+}
+impl<N: Nat> SumZero for N {
+    type Proof = Equal<N, <N as Sum<Zero>>::Output>;
+}
+
+type ZeroSumStatement<N> = Equal<N, <Zero as Sum<N>>::Output>;
+induct! {
+    /// `0 + n = n`. `Sum` recurses on its right argument, so this takes induction:
+    /// `0 + (n + 1) = 1 + n = (0 + n) + 1 = n + 1`, by `SuccessorSum` and then induction.
+    ///
+    /// `SuccessorSum` is proved above, and rustc checks the rest for all naturals at once.
+    pub trait ZeroSum for N over Nat;
+    base: ZeroSumStatement<Zero>;
+    step: ZeroSumStatement<N> => ZeroSumStatement<Successor<N>> where {
+        // `1 + n = (0 + n) + 1`.
+        N: SuccessorSum<Zero>,
+        One: Sum<N, Output = Successor<<Zero as Sum<N>>::Output>>,
+        // Induction hypothesis: `0 + n = n`.
+        Zero: Sum<N, Output = N>,
+    };
+}
+
+/// `n·1 = n·0 + n = 0 + n = n`, by `ZeroSum`.
+pub trait ProductOne: Nat {
+    type Proof;
+}
+impl<N: Nat> ProductOne for N where
+    N: ZeroSum,
+    Zero: Sum<N, Output = N>,
+{
+    type Proof = Equal<N, <N as Product<One>>::Output>;
+}
+
+type OneProductStatement<N> = Equal<N, <One as Product<N>>::Output>;
+induct! {
+    /// `1·n = n`, by induction: `1·(n + 1) = 1·n + 1 = n + 1`.
+    pub trait OneProduct for N over Nat;
+    base: OneProductStatement<Zero>;
+    step: OneProductStatement<N> => OneProductStatement<Successor<N>> where {
+        // Induction hypothesis: `1·n = n`.
+        One: Product<N, Output = N>,
+    };
+}
+
+proofs! {
+    <Four as SumZero>::Proof;
+    <Zero as ZeroSum>::Proof;
+    <Six as ZeroSum>::Proof;
+    <Five as ProductOne>::Proof;
+    <Zero as OneProduct>::Proof;
+    <Seven as OneProduct>::Proof;
+}
+
+// ### Addition is associative and commutative.
+
+/// `(a + b) + c = a + (b + c)`, by induction on `c`:
+/// - `(a + b) + 0 = a + b = a + (b + 0)`.
+/// - `(a + b) + (k + 1) = ((a + b) + k) + 1 = (a + (b + k)) + 1`, by `SuccessorSum` and induction,
+///   and `a + (b + (k + 1)) = a + ((b + k) + 1) = (a + (b + k)) + 1` by `SuccessorSum` twice.
+///
+/// `SuccessorSum` is proved above, so this holds for every `a`, `b` and `c` outright.
+pub trait SumAssociative<A: Nat, B: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat, B: Nat> SumAssociative<A, B> for Zero where
+    A: Sum<B>,
+    SumMN<A, B>: Nat,
+{
+    type Proof = Equal<SumMN<SumMN<A, B>, Zero>, SumMN<A, SumMN<B, Zero>>>;
+}
+impl<A: Nat, B: Nat, K: Nat> SumAssociative<A, B> for Successor<K> where
+    A: Sum<B>,
+    SumMN<A, B>: Nat,
+    B: Sum<K>,
+    SumMN<B, K>: Nat,
+    A: Sum<SumMN<B, K>>,
+    SumMN<A, SumMN<B, K>>: Nat,
+    // `(a + b) + (k + 1) = ((a + b) + k) + 1`.
+    K: SuccessorSum<SumMN<A, B>>,
+    Successor<SumMN<A, B>>: Sum<K, Output = Successor<SumMN<SumMN<A, B>, K>>>,
+    // `b + (k + 1) = (b + k) + 1`, and `a + ((b + k) + 1) = (a + (b + k)) + 1`.
+    K: SuccessorSum<B>,
+    Successor<B>: Sum<K, Output = Successor<SumMN<B, K>>>,
+    SumMN<B, K>: SuccessorSum<A>,
+    Successor<A>: Sum<SumMN<B, K>, Output = Successor<SumMN<A, SumMN<B, K>>>>,
+    // Induction hypothesis: `(a + b) + k = a + (b + k)`.
+    K: SumAssociative<A, B>,
+    SumMN<A, B>: Sum<K, Output = SumMN<A, SumMN<B, K>>>,
+{
+    type Proof = Equal<SumMN<SumMN<A, B>, Successor<K>>, SumMN<A, SumMN<B, Successor<K>>>>;
+}
+
+/// `a + b = b + a`, by induction on `b`:
+/// - `a + 0 = a = 0 + a`, which is `ZeroSum`.
+/// - `a + (k + 1) = (a + k) + 1 = (k + a) + 1 = (k + 1) + a`, by `SuccessorSum`, induction,
+///   and `SuccessorSum` again.
+pub trait SumCommutes<A: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat> SumCommutes<A> for Zero where
+    A: ZeroSum,
+    Zero: Sum<A, Output = A>,
+{
+    type Proof = Equal<SumMN<A, Zero>, SumMN<Zero, A>>;
+}
+impl<A: Nat, K: Nat> SumCommutes<A> for Successor<K> where
+    K: Sum<A>,
+    SumMN<K, A>: Nat,
+    // `a + (k + 1) = (a + 1) + k = (a + k) + 1`.
+    K: SuccessorSum<A>,
+    Successor<A>: Sum<K, Output = Successor<SumMN<A, K>>>,
+    // `(k + 1) + a = (k + a) + 1`.
+    A: SuccessorSum<K>,
+    Successor<K>: Sum<A, Output = Successor<SumMN<K, A>>>,
+    // Induction hypothesis: `a + k = k + a`.
+    K: SumCommutes<A>,
+    A: Sum<K, Output = SumMN<K, A>>,
+{
+    type Proof = Equal<SumMN<A, Successor<K>>, SumMN<Successor<K>, A>>;
+}
+
+/// `(a + b) + c = (a + c) + b`, with `Self` as `a`. Both are `a + (b + c)`: `SumAssociative`,
+/// then `SumCommutes` on `b + c`, then `SumAssociative` back.
+pub trait SumSwap<B: Nat, C: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat, B: Nat, C: Nat> SumSwap<B, C> for A where
+    A: Sum<B> + Sum<C>,
+    SumMN<A, B>: Nat,
+    SumMN<A, C>: Nat,
+    B: Sum<C>,
+    SumMN<B, C>: Nat,
+    C: Sum<B>,
+    SumMN<C, B>: Nat,
+    A: Sum<SumMN<B, C>>,
+    SumMN<A, SumMN<B, C>>: Nat,
+    // `(a + b) + c = a + (b + c)`.
+    C: SumAssociative<A, B>,
+    SumMN<A, B>: Sum<C, Output = SumMN<A, SumMN<B, C>>>,
+    // `c + b = b + c`.
+    B: SumCommutes<C>,
+    C: Sum<B, Output = SumMN<B, C>>,
+    // `(a + c) + b = a + (c + b)`.
+    B: SumAssociative<A, C>,
+    SumMN<A, C>: Sum<B, Output = SumMN<A, SumMN<C, B>>>,
+{
+    type Proof = Equal<SumMN<SumMN<A, B>, C>, SumMN<SumMN<A, C>, B>>;
+}
+
+/// `((a + b) + c) + d = (a + d) + (b + c)`, with `Self` as `a`, by `SumAssociative` on the
+/// inner sum and then `SumSwap`.
+pub trait SumShuffle<B: Nat, C: Nat, D: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat, B: Nat, C: Nat, D: Nat> SumShuffle<B, C, D> for A where
+    A: Sum<B> + Sum<D> + Sum<SumMN<B, C>>,
+    B: Sum<C>,
+    SumMN<B, C>: Nat + Sum<D>,
+    D: Sum<SumMN<B, C>>,
+    SumMN<A, B>: Nat,
+    SumMN<A, D>: Nat + Sum<SumMN<B, C>>,
+    // `(a + b) + c = a + (b + c)`.
+    C: SumAssociative<A, B>,
+    SumMN<A, B>: Sum<C, Output = SumMN<A, SumMN<B, C>>>,
+    // `(a + (b + c)) + d = (a + d) + (b + c)`.
+    A: SumSwap<SumMN<B, C>, D>,
+    SumMN<A, SumMN<B, C>>: Sum<D, Output = SumMN<SumMN<A, D>, SumMN<B, C>>>,
+{
+    type Proof = Equal<SumMN<SumMN<SumMN<A, B>, C>, D>, SumMN<SumMN<A, D>, SumMN<B, C>>>;
+}
+
+/// `(a + b) + (c + d) = (a + c) + (b + d)`, with `Self` as `a`, by `SumAssociative` to
+/// `((a + b) + c) + d`, `SumSwap` on the inner sum, and `SumAssociative` back.
+pub trait SumExchange<B: Nat, C: Nat, D: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat, B: Nat, C: Nat, D: Nat> SumExchange<B, C, D> for A where
+    A: Sum<B> + Sum<C> + Sum<SumMN<B, C>>,
+    B: Sum<C> + Sum<D>,
+    C: Sum<B> + Sum<D>,
+    SumMN<B, C>: Nat,
+    SumMN<C, B>: Nat,
+    SumMN<A, B>: Nat + Sum<C> + Sum<SumMN<C, D>>,
+    SumMN<A, C>: Nat + Sum<B> + Sum<SumMN<B, D>>,
+    SumMN<B, D>: Nat,
+    SumMN<C, D>: Nat,
+    SumMN<SumMN<A, B>, C>: Nat + Sum<D>,
+    SumMN<SumMN<A, C>, B>: Nat + Sum<D>,
+    // `(a + b) + (c + d) = ((a + b) + c) + d`,
+    D: SumAssociative<SumMN<A, B>, C>,
+    SumMN<A, B>: Sum<SumMN<C, D>, Output = SumMN<SumMN<SumMN<A, B>, C>, D>>,
+    // `(a + b) + c = (a + c) + b`,
+    A: SumSwap<B, C>,
+    SumMN<A, B>: Sum<C, Output = SumMN<SumMN<A, C>, B>>,
+    // and `((a + c) + b) + d = (a + c) + (b + d)`.
+    D: SumAssociative<SumMN<A, C>, B>,
+    SumMN<SumMN<A, C>, B>: Sum<D, Output = SumMN<SumMN<A, C>, SumMN<B, D>>>,
+{
+    type Proof = Equal<SumMN<SumMN<A, B>, SumMN<C, D>>, SumMN<SumMN<A, C>, SumMN<B, D>>>;
+}
+
+/// `a + (b + 1) = (b + a) + 1`, by `SuccessorSum` and `SumCommutes`.
+///
+/// `Self` is `b + 1` rather than `b`. A step that only knows some `p` is `b + 1` by induction
+/// can't rewrite `a + p` into `a + (b + 1)` itself, but it can ask for `p: SumOntoSuccessor<A, B>`,
+/// which only holds when `p` is `b + 1`.
+pub trait SumOntoSuccessor<A: Nat, B: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat, B: Nat> SumOntoSuccessor<A, B> for Successor<B> where
+    A: Sum<B>,
+    SumMN<A, B>: Nat,
+    B: Sum<A>,
+    SumMN<B, A>: Nat,
+    // `a + (b + 1) = (a + 1) + b = (a + b) + 1`.
+    B: SuccessorSum<A>,
+    Successor<A>: Sum<B, Output = Successor<SumMN<A, B>>>,
+    // `a + b = b + a`.
+    B: SumCommutes<A>,
+    A: Sum<B, Output = SumMN<B, A>>,
+{
+    type Proof = Equal<SumMN<A, Successor<B>>, Successor<SumMN<B, A>>>;
+}
+
+proofs! {
+    <Three as SumSwap<Two, Four>>::Proof;
+    <Zero as SumSwap<Five, One>>::Proof;
+    <Two as SumShuffle<One, Three, Four>>::Proof;
+    <One as SumExchange<Three, Zero, Two>>::Proof;
+    <Four as SumOntoSuccessor<Five, Three>>::Proof;
+    <Three as SumAssociative<Two, Four>>::Proof;
+    <Zero as SumAssociative<Five, One>>::Proof;
+    <Four as SumCommutes<Three>>::Proof;
+    <Zero as SumCommutes<Six>>::Proof;
+}
+
+// ### Multiplication is commutative.
+
+type ZeroProductStatement<N> = Equal<Zero, <Zero as Product<N>>::Output>;
+induct! {
+    /// `0·n = 0`. `Product` recurses on its right argument, so this takes induction.
+    pub trait ZeroProduct for N over Nat;
+    base: ZeroProductStatement<Zero>;
+    step: ZeroProductStatement<N> => ZeroProductStatement<Successor<N>> where {
+        // Induction hypothesis: `0·n = 0`.
+        Zero: Product<N, Output = Zero>,
+    };
+}
+
+type MulMN<M, N> = <M as Product<N>>::Output;
+
+/// `n(m + 1) = nm + n`. This is how `Product` is defined, so there's nothing to it
+/// beyond rustc agreeing, for every `n` and `m` at once.
+pub trait ProductSuccessor<M: Nat>: Nat {
+    type Proof;
+}
+impl<N: Nat, M: Nat> ProductSuccessor<M> for N where
+    N: Product<M>,
+    MulMN<N, M>: Sum<N>,
+{
+    type Proof = Equal<<N as Product<Successor<M>>>::Output, <MulMN<N, M> as Sum<N>>::Output>;
+}
+
+/// `(m + 1)n = mn + n`, by induction on `n`.
+/// This is distributivity, with `(m + 1)n = mn + 1n`.
+pub trait SuccessorProduct<M: Nat>: Nat {
+    type Proof;
+}
+impl<M: Nat> SuccessorProduct<M> for Zero {
+    type Proof = Equal<<Successor<M> as Product<Zero>>::Output, <MulMN<M, Zero> as Sum<Zero>>::Output>;
+}
+impl<M: Nat, N: Nat> SuccessorProduct<M> for Successor<N> where
+    M: Product<N>,
+    MulMN<M, N>: Nat + Sum<N> + Sum<M>,
+    <MulMN<M, N> as Sum<N>>::Output: Nat,
+    <MulMN<M, N> as Sum<M>>::Output: Nat + Sum<Successor<N>>,
+    // Induction hypothesis: `(m + 1)n = mn + n`.
+    N: SuccessorProduct<M>,
+    Successor<M>: Product<N, Output = <MulMN<M, N> as Sum<N>>::Output>,
+    // `(mn + n) + (m + 1) = (mn + m) + (n + 1)`. Both are `mn` plus `(n + m) + 1`, by
+    // `SumAssociative`, `SuccessorSum` and `SumCommutes`.
+    Successor<M>: SumAssociative<MulMN<M, N>, N>,
+    SumMN<MulMN<M, N>, N>: Sum<Successor<M>, Output = SumMN<MulMN<M, N>, SumMN<N, Successor<M>>>>,
+    Successor<N>: SumAssociative<MulMN<M, N>, M>,
+    SumMN<MulMN<M, N>, M>: Sum<Successor<N>, Output = SumMN<MulMN<M, N>, SumMN<M, Successor<N>>>>,
+    M: Sum<N>,
+    SumMN<M, N>: Nat,
+    MulMN<M, N>: Sum<Successor<SumMN<M, N>>>,
+    M: SuccessorSum<N>,
+    Successor<N>: Sum<M, Output = Successor<SumMN<N, M>>>,
+    N: SuccessorSum<M>,
+    Successor<M>: Sum<N, Output = Successor<SumMN<M, N>>>,
+    M: SumCommutes<N>,
+    N: Sum<M, Output = SumMN<M, N>>,
+{
+    type Proof = Equal<
+        <Successor<M> as Product<Successor<N>>>::Output,
+        <MulMN<M, Successor<N>> as Sum<Successor<N>>>::Output,
+    >;
+}
+
+/// `ab = ba` for all `a` and `b`, by induction on `b`:
+/// - `a·0 = 0 = 0·a`, which is `ZeroProduct`.
+/// - `a(b + 1) = ab + a = ba + a = (b + 1)a`, by `ProductSuccessor`, induction, and then `SuccessorProduct`.
+///
+/// `ZeroProduct`, `ProductSuccessor` and `SuccessorProduct` are proved above, the last of them
+/// using `SumAssociative` and `SumCommutes`, so this holds for every `a` and `b` outright.
+pub trait ProductCommutes<A: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat> ProductCommutes<A> for Zero where
+    A: ZeroProduct,
+    Zero: Product<A, Output = Zero>,
+{
+    type Proof = Equal<<A as Product<Zero>>::Output, <Zero as Product<A>>::Output>;
+}
+impl<A: Nat, B: Nat> ProductCommutes<A> for Successor<B> where
+    A: Product<B>,
+    MulMN<A, B>: Nat + Sum<A>,
+    // `a(b + 1) = ab + a`.
+    A: ProductSuccessor<B>,
+    // `(b + 1)a = ba + a`.
+    A: SuccessorProduct<B>,
+    B: Product<A>,
+    MulMN<B, A>: Nat + Sum<A>,
+    Successor<B>: Product<A, Output = <MulMN<B, A> as Sum<A>>::Output>,
+    // Induction hypothesis: `ba = ab`.
+    B: ProductCommutes<A> + Product<A, Output = MulMN<A, B>>,
+{
+    type Proof = Equal<<A as Product<Successor<B>>>::Output, <Successor<B> as Product<A>>::Output>;
+}
+
+proofs! {
+    <Three as ZeroProduct>::Proof;
+    <Two as ProductSuccessor<Three>>::Proof;
+    <Four as ProductSuccessor<Zero>>::Proof;
+    Equal<<Three as Product<Three>>::Output, <<Three as Product<Two>>::Output as Sum<Three>>::Output>;
+    <Two as SuccessorProduct<Three>>::Proof;
+    <Zero as ProductCommutes<Four>>::Proof;
+    <Two as ProductCommutes<Three>>::Proof;
+    <Four as ProductCommutes<Three>>::Proof;
+    <Five as ProductCommutes<Two>>::Proof;
+    Equal<<Two as Product<Five>>::Output, <Five as Product<Two>>::Output>;
+}
+
+
+// ### Multiplication distributes over addition, and is associative.
+
+/// `a(b + c) = ab + ac`, by induction on `c`:
+/// - `a(b + 0) = ab = ab + a·0`.
+/// - `a(b + (k + 1)) = a((b + k) + 1) = a(b + k) + a = (ab + ak) + a`, by `SuccessorSum` and
+///   induction, and `SumAssociative` moves the brackets to give `ab + (ak + a) = ab + a(k + 1)`.
+///
+/// `SuccessorSum` and `SumAssociative` are proved above, so this holds for every `a`, `b` and `c`
+/// outright. The other way round, `(a + d)c = ac + dc`, is `ProductMonotone` below.
+pub trait ProductDistributes<A: Nat, B: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat, B: Nat> ProductDistributes<A, B> for Zero where
+    A: Product<B>,
+    MulMN<A, B>: Nat,
+{
+    type Proof = Equal<MulMN<A, SumMN<B, Zero>>, SumMN<MulMN<A, B>, MulMN<A, Zero>>>;
+}
+impl<A: Nat, B: Nat, K: Nat> ProductDistributes<A, B> for Successor<K> where
+    // Names for `b + k`, `ab` and `ak`, and their sums.
+    B: Sum<K>,
+    SumMN<B, K>: Nat,
+    A: Product<B> + Product<K> + Product<SumMN<B, K>>,
+    MulMN<A, B>: Nat + Sum<MulMN<A, K>> + Sum<SumMN<MulMN<A, K>, A>>,
+    MulMN<A, K>: Nat + Sum<A>,
+    SumMN<MulMN<A, K>, A>: Nat,
+    SumMN<MulMN<A, B>, MulMN<A, K>>: Nat,
+    // `b + (k + 1) = (b + k) + 1`, which is `SuccessorSum`.
+    K: SuccessorSum<B>,
+    Successor<B>: Sum<K, Output = Successor<SumMN<B, K>>>,
+    // Induction hypothesis: `a(b + k) = ab + ak`.
+    K: ProductDistributes<A, B>,
+    A: Product<SumMN<B, K>, Output = SumMN<MulMN<A, B>, MulMN<A, K>>>,
+    // `(ab + ak) + a = ab + (ak + a)`, by `SumAssociative`.
+    A: SumAssociative<MulMN<A, B>, MulMN<A, K>>,
+    SumMN<MulMN<A, B>, MulMN<A, K>>: Sum<A, Output = SumMN<MulMN<A, B>, SumMN<MulMN<A, K>, A>>>,
+{
+    type Proof = Equal<MulMN<A, SumMN<B, Successor<K>>>, SumMN<MulMN<A, B>, MulMN<A, Successor<K>>>>;
+}
+
+/// `(ab)c = a(bc)`, by induction on `c`:
+/// - `(ab)·0 = 0 = a·0 = a(b·0)`.
+/// - `(ab)(k + 1) = (ab)k + ab = a(bk) + ab`, by induction, and that's `a(bk + b) = a(b(k + 1))`
+///   by `ProductDistributes`.
+///
+/// `ProductDistributes` is proved above, so this holds for every `a`, `b` and `c` outright.
+pub trait ProductAssociative<A: Nat, B: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat, B: Nat> ProductAssociative<A, B> for Zero where
+    A: Product<B>,
+    MulMN<A, B>: Nat,
+{
+    type Proof = Equal<MulMN<MulMN<A, B>, Zero>, MulMN<A, MulMN<B, Zero>>>;
+}
+impl<A: Nat, B: Nat, K: Nat> ProductAssociative<A, B> for Successor<K> where
+    // Names for `ab`, `bk` and the products of them.
+    A: Product<B>,
+    MulMN<A, B>: Nat + Product<K>,
+    B: Product<K>,
+    MulMN<B, K>: Nat + Sum<B>,
+    A: Product<MulMN<B, K>> + Product<SumMN<MulMN<B, K>, B>>,
+    MulMN<A, MulMN<B, K>>: Nat + Sum<MulMN<A, B>>,
+    // Induction hypothesis: `(ab)k = a(bk)`.
+    K: ProductAssociative<A, B>,
+    MulMN<A, B>: Product<K, Output = MulMN<A, MulMN<B, K>>>,
+    // `a(bk + b) = a(bk) + ab`, by `ProductDistributes`.
+    B: ProductDistributes<A, MulMN<B, K>>,
+    A: Product<SumMN<MulMN<B, K>, B>, Output = SumMN<MulMN<A, MulMN<B, K>>, MulMN<A, B>>>,
+{
+    type Proof = Equal<MulMN<MulMN<A, B>, Successor<K>>, MulMN<A, MulMN<B, Successor<K>>>>;
+}
+
+/// `(ab)c = (ac)b`, by induction on `c`:
+/// - `(ab)·0 = 0 = 0·b = (a·0)b`, by `ZeroProduct`.
+/// - `(ab)(k + 1) = (ab)k + ab = (ak)b + ab`, by induction, and that's `(ak + a)b = (a(k + 1))b`
+///   by `ProductMonotone`.
+///
+/// `ZeroProduct` is proved above, and `ProductMonotone` below, so this holds outright.
+pub trait ProductSwap<A: Nat, B: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat, B: Nat> ProductSwap<A, B> for Zero where
+    A: Product<B>,
+    MulMN<A, B>: Nat,
+    B: ZeroProduct,
+    Zero: Product<B, Output = Zero>,
+{
+    type Proof = Equal<MulMN<MulMN<A, B>, Zero>, MulMN<MulMN<A, Zero>, B>>;
+}
+impl<A: Nat, B: Nat, K: Nat> ProductSwap<A, B> for Successor<K> where
+    // Names for `ab`, `ak`, and the products and sums of them.
+    A: Product<B> + Product<K>,
+    MulMN<A, B>: Nat + Product<K>,
+    MulMN<A, K>: Nat + Sum<A> + Product<B>,
+    SumMN<MulMN<A, K>, A>: Nat + Product<B>,
+    MulMN<MulMN<A, K>, B>: Nat + Sum<MulMN<A, B>>,
+    // Induction hypothesis: `(ab)k = (ak)b`.
+    K: ProductSwap<A, B>,
+    MulMN<A, B>: Product<K, Output = MulMN<MulMN<A, K>, B>>,
+    // `(ak + a)b = (ak)b + ab`, by `ProductMonotone`.
+    B: ProductMonotone<MulMN<A, K>, A>,
+    SumMN<MulMN<A, K>, A>: Product<B, Output = SumMN<MulMN<MulMN<A, K>, B>, MulMN<A, B>>>,
+{
+    type Proof = Equal<MulMN<MulMN<A, B>, Successor<K>>, MulMN<MulMN<A, Successor<K>>, B>>;
+}
+
+/// `(ab)(cd) = (ac)(bd)`, by induction on `d`:
+/// - `(ab)(c·0) = 0 = (ac)(b·0)`.
+/// - `(ab)(c(k + 1)) = (ab)(ck + c) = (ab)(ck) + (ab)c`, by `ProductDistributes`. That's
+///   `(ac)(bk) + (ac)b` by induction and `ProductSwap`, and `ProductDistributes` puts it back
+///   together as `(ac)(bk + b) = (ac)(b(k + 1))`.
+pub trait ProductExchange<A: Nat, B: Nat, C: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat, B: Nat, C: Nat> ProductExchange<A, B, C> for Zero where
+    A: Product<B> + Product<C>,
+    MulMN<A, B>: Nat,
+    MulMN<A, C>: Nat,
+{
+    type Proof = Equal<MulMN<MulMN<A, B>, MulMN<C, Zero>>, MulMN<MulMN<A, C>, MulMN<B, Zero>>>;
+}
+impl<A: Nat, B: Nat, C: Nat, K: Nat> ProductExchange<A, B, C> for Successor<K> where
+    // Names for `ab`, `ac`, `bk`, `ck`, and the products and sums of them.
+    A: Product<B> + Product<C>,
+    B: Product<K>,
+    C: Product<K>,
+    MulMN<A, B>: Nat + Product<C> + Product<MulMN<C, K>> + Product<SumMN<MulMN<C, K>, C>>,
+    MulMN<A, C>: Nat + Product<B> + Product<MulMN<B, K>> + Product<SumMN<MulMN<B, K>, B>>,
+    MulMN<B, K>: Nat + Sum<B>,
+    MulMN<C, K>: Nat + Sum<C>,
+    SumMN<MulMN<B, K>, B>: Nat,
+    SumMN<MulMN<C, K>, C>: Nat,
+    MulMN<MulMN<A, C>, B>: Nat,
+    MulMN<MulMN<A, C>, MulMN<B, K>>: Nat + Sum<MulMN<MulMN<A, C>, B>>,
+    // `(ab)(ck + c) = (ab)(ck) + (ab)c`, by `ProductDistributes`.
+    C: ProductDistributes<MulMN<A, B>, MulMN<C, K>>,
+    MulMN<A, B>: Product<
+        SumMN<MulMN<C, K>, C>,
+        Output = SumMN<MulMN<MulMN<A, B>, MulMN<C, K>>, MulMN<MulMN<A, B>, C>>,
+    >,
+    // Induction hypothesis: `(ab)(ck) = (ac)(bk)`.
+    K: ProductExchange<A, B, C>,
+    MulMN<A, B>: Product<MulMN<C, K>, Output = MulMN<MulMN<A, C>, MulMN<B, K>>>,
+    // `(ab)c = (ac)b`, by `ProductSwap`.
+    C: ProductSwap<A, B>,
+    MulMN<A, B>: Product<C, Output = MulMN<MulMN<A, C>, B>>,
+    // `(ac)(bk + b) = (ac)(bk) + (ac)b`, by `ProductDistributes`.
+    B: ProductDistributes<MulMN<A, C>, MulMN<B, K>>,
+    MulMN<A, C>: Product<
+        SumMN<MulMN<B, K>, B>,
+        Output = SumMN<MulMN<MulMN<A, C>, MulMN<B, K>>, MulMN<MulMN<A, C>, B>>,
+    >,
+{
+    type Proof = Equal<MulMN<MulMN<A, B>, MulMN<C, Successor<K>>>, MulMN<MulMN<A, C>, MulMN<B, Successor<K>>>>;
+}
+
+proofs! {
+    <Zero as ProductDistributes<Three, Two>>::Proof;
+    <Three as ProductDistributes<Two, Four>>::Proof;
+    <Two as ProductDistributes<Zero, Five>>::Proof;
+    <Zero as ProductAssociative<Four, Two>>::Proof;
+    <Three as ProductAssociative<Two, Two>>::Proof;
+    <Two as ProductAssociative<One, Four>>::Proof;
+    <Zero as ProductSwap<Two, Three>>::Proof;
+    <Three as ProductSwap<Two, One>>::Proof;
+    <Zero as ProductExchange<Four, One, Two>>::Proof;
+    <Two as ProductExchange<One, Three, Two>>::Proof;
+    <One as ProductExchange<Two, Two, Three>>::Proof;
+    // `2(3 + 4) = 6 + 8`, and `(2·2)·3 = 2(2·3)`.
+    Equal<<Two as Product<Seven>>::Output, <Six as Sum<Eight>>::Output>;
+    Equal<<Four as Product<Three>>::Output, <Two as Product<Six>>::Output>;
+}
+
+
+// ### Adding and multiplying preserve order.
+//
+// `a ≤ b` when `b = a + d` for some `d`. The lemmas below carry `d` along, which can be done
+// for every `a`, `b` and `c` at once, and turn into `LessThanOrEqual` at concrete numbers.
+
+type SumMN<M, N> = <M as Sum<N>>::Output;
+
+/// `(a + d) + c = (a + c) + d`: if `a ≤ b = a + d`, then `a + c ≤ b + c`, by the same `d`.
+///
+/// By induction on `c`:
+/// - `(a + d) + 0 = a + d = (a + 0) + d`.
+/// - `(a + d) + (k + 1) = ((a + d) + k) + 1 = ((a + k) + d) + 1`, by `SuccessorSum` and induction,
+///   and `(a + (k + 1)) + d = ((a + k) + 1) + d = ((a + k) + d) + 1` by `SuccessorSum` twice.
+///
+/// `SuccessorSum` is proved above, so this holds for every `a`, `d` and `c` outright.
+pub trait SumMonotone<A: Nat, D: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat, D: Nat> SumMonotone<A, D> for Zero where
+    A: Sum<D>,
+    SumMN<A, D>: Nat,
+{
+    type Proof = Equal<SumMN<SumMN<A, D>, Zero>, SumMN<SumMN<A, Zero>, D>>;
+}
+impl<A: Nat, D: Nat, K: Nat> SumMonotone<A, D> for Successor<K> where
+    A: Sum<D> + Sum<K>,
+    SumMN<A, D>: Nat + Sum<K>,
+    SumMN<A, K>: Nat + Sum<D>,
+    SumMN<SumMN<A, D>, K>: Nat,
+    SumMN<SumMN<A, K>, D>: Nat,
+    // `(a + d) + (k + 1) = ((a + d) + k) + 1`.
+    K: SuccessorSum<SumMN<A, D>>,
+    Successor<SumMN<A, D>>: Sum<K, Output = Successor<SumMN<SumMN<A, D>, K>>>,
+    // `(a + 1) + k = (a + k) + 1`, and `((a + k) + 1) + d = ((a + k) + d) + 1`.
+    K: SuccessorSum<A>,
+    Successor<A>: Sum<K, Output = Successor<SumMN<A, K>>>,
+    D: SuccessorSum<SumMN<A, K>>,
+    Successor<SumMN<A, K>>: Sum<D, Output = Successor<SumMN<SumMN<A, K>, D>>>,
+    // Induction hypothesis: `(a + d) + k = (a + k) + d`.
+    K: SumMonotone<A, D>,
+    SumMN<A, D>: Sum<K, Output = SumMN<SumMN<A, K>, D>>,
+{
+    type Proof = Equal<SumMN<SumMN<A, D>, Successor<K>>, SumMN<SumMN<A, Successor<K>>, D>>;
+}
+
+/// `(a + d)c = ac + dc`: if `a ≤ b = a + d`, then `ac ≤ bc`, by `dc`.
+///
+/// By induction on `c`:
+/// - `(a + d)·0 = 0 = 0 + 0`.
+/// - `(a + d)(k + 1) = (a + d)k + (a + d) = (ak + dk) + (a + d)`, by induction, and
+///   regrouping that is `(ak + a) + (dk + d) = a(k + 1) + d(k + 1)`.
+///
+/// The regrouping swaps `dk` and `a` with `SumMonotone`, and `SumAssociative` moves the
+/// brackets either side of that. All of them are proved above, so this holds outright.
+pub trait ProductMonotone<A: Nat, D: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat, D: Nat> ProductMonotone<A, D> for Zero where
+    A: Sum<D>,
+    SumMN<A, D>: Nat,
+{
+    type Proof = Equal<MulMN<SumMN<A, D>, Zero>, SumMN<MulMN<A, Zero>, MulMN<D, Zero>>>;
+}
+impl<A: Nat, D: Nat, K: Nat> ProductMonotone<A, D> for Successor<K> where
+    // Names for `b = a + d`, `ak` and `dk`, and their sums.
+    A: Sum<D> + Product<K>,
+    D: Product<K>,
+    SumMN<A, D>: Nat + Product<K>,
+    MulMN<A, K>: Nat + Sum<A> + Sum<MulMN<D, K>>,
+    MulMN<D, K>: Nat + Sum<D>,
+    SumMN<MulMN<A, K>, A>: Nat + Sum<MulMN<D, K>> + Sum<SumMN<MulMN<D, K>, D>>,
+    SumMN<MulMN<D, K>, D>: Nat,
+    SumMN<MulMN<A, K>, MulMN<D, K>>: Nat + Sum<SumMN<A, D>> + Sum<A>,
+    SumMN<SumMN<MulMN<A, K>, MulMN<D, K>>, A>: Nat + Sum<D>,
+    SumMN<SumMN<MulMN<A, K>, A>, MulMN<D, K>>: Nat + Sum<D>,
+    // Induction hypothesis: `(a + d)k = ak + dk`.
+    K: ProductMonotone<A, D>,
+    SumMN<A, D>: Product<K, Output = SumMN<MulMN<A, K>, MulMN<D, K>>>,
+    // `SumAssociative`: `(ak + dk) + (a + d) = ((ak + dk) + a) + d`,
+    D: SumAssociative<SumMN<MulMN<A, K>, MulMN<D, K>>, A>,
+    SumMN<MulMN<A, K>, MulMN<D, K>>: Sum<
+        SumMN<A, D>,
+        Output = SumMN<SumMN<SumMN<MulMN<A, K>, MulMN<D, K>>, A>, D>,
+    >,
+    // `SumMonotone`: `(ak + dk) + a = (ak + a) + dk`,
+    A: SumMonotone<MulMN<A, K>, MulMN<D, K>>,
+    SumMN<MulMN<A, K>, MulMN<D, K>>: Sum<A, Output = SumMN<SumMN<MulMN<A, K>, A>, MulMN<D, K>>>,
+    // and `SumAssociative` again: `((ak + a) + dk) + d = (ak + a) + (dk + d)`.
+    D: SumAssociative<SumMN<MulMN<A, K>, A>, MulMN<D, K>>,
+    SumMN<SumMN<MulMN<A, K>, A>, MulMN<D, K>>: Sum<
+        D,
+        Output = SumMN<SumMN<MulMN<A, K>, A>, SumMN<MulMN<D, K>, D>>,
+    >,
+{
+    type Proof = Equal<MulMN<SumMN<A, D>, Successor<K>>, SumMN<MulMN<A, Successor<K>>, MulMN<D, Successor<K>>>>;
+}
+
+/// `a ≤ b` gives `ac ≤ bc`, by way of `ProductMonotone` with `d = b - a`.
+///
+/// `a + (b - a) = b` comes from `SumUndoesDifference` and `SumCommutes`. That `ac + dc` is
+/// at least `ac` is a subtraction, which rustc does at each concrete `a`, `b` and `c`.
+pub trait ProductPreservesOrder<B, C> {
+    type Proof;
+}
+impl<A: Nat, B: Nat, C: Nat> ProductPreservesOrder<B, C> for A where
+    // Hypothesis: `a ≤ b`.
+    A: LessThanOrEqual<B>,
+    B: Difference<A>,
+    <B as Difference<A>>::Output: Nat + Product<C>,
+    MulMN<<B as Difference<A>>::Output, C>: Nat,
+    // `a + (b - a) = (b - a) + a = b`, by `SumCommutes` and `SumUndoesDifference`.
+    <B as Difference<A>>::Output: SumCommutes<A>,
+    B: SumUndoesDifference<A>,
+    <B as Difference<A>>::Output: Sum<A, Output = B>,
+    A: Sum<<B as Difference<A>>::Output, Output = B> + Product<C>,
+    B: Product<C>,
+    MulMN<A, C>: Nat + Sum<MulMN<<B as Difference<A>>::Output, C>>,
+    // `bc = ac + dc`.
+    C: ProductMonotone<A, <B as Difference<A>>::Output>,
+    MulMN<B, C>: Nat + Difference<MulMN<A, C>>,
+{
+    type Proof = <MulMN<A, C> as LessThanOrEqual<MulMN<B, C>>>::Output;
+}
+
+proofs! {
+    <Zero as SumMonotone<Two, Three>>::Proof;
+    <Four as SumMonotone<Two, Three>>::Proof;
+    <Three as SumMonotone<Zero, Five>>::Proof;
+    <Zero as ProductMonotone<Two, One>>::Proof;
+    <Two as ProductMonotone<Two, One>>::Proof;
+    <Three as ProductMonotone<One, Two>>::Proof;
+    // `2 ≤ 3`, so `2·3 ≤ 3·3`, and so on.
+    <Two as ProductPreservesOrder<Three, Three>>::Proof;
+    <One as ProductPreservesOrder<Four, Two>>::Proof;
+    <Two as ProductPreservesOrder<Two, Four>>::Proof;
+    <Three as ProductPreservesOrder<Five, Zero>>::Proof;
+    <<Two as Product<Three>>::Output as LessThanOrEqual<<Three as Product<Three>>::Output>>::Output;
+}
+// The order has to be there to begin with:
+// proof! { <Three as ProductPreservesOrder<Two, Two>>::Proof }
+
+// ### `≤` is antisymmetric.
+
+/// `a ≤ b` and `b ≤ a` give `a = b`, by induction on both at once:
+/// - `0 ≤ 0` both ways, and `0 = 0`.
+/// - `a + 1 ≤ b + 1` is `a ≤ b`, and the same the other way, so `a = b` by induction, and then
+///   `a + 1 = b + 1`.
+///
+/// If only one of them is zero, one of the two `≤`s doesn't hold, and there's no impl.
+/// The conclusion is carried as `TypeEq` giving `True`, since that can be assumed for the
+/// induction hypothesis. At concrete numbers, it's the same as `Equal<A, B>`.
+///
+/// This is part of what lets `Min`, `Max` and `Clamp` treat `≤` as a partial order.
+pub trait LessThanOrEqualAntisymmetric<B: Nat>: Nat + LessThanOrEqual<B> {
+    type Proof;
+}
+impl LessThanOrEqualAntisymmetric<Zero> for Zero {
+    type Proof = Equal<True, <Zero as TypeEq<Zero>>::Output>;
+}
+impl<A: Nat, B: Nat> LessThanOrEqualAntisymmetric<Successor<B>> for Successor<A> where
+    // `a ≤ b` and `b ≤ a`.
+    B: Difference<A>,
+    A: Difference<B>,
+    // Induction hypothesis: `a = b`.
+    A: LessThanOrEqualAntisymmetric<B>,
+    A: TypeEq<B, Output = True>,
+{
+    type Proof = Equal<True, <Successor<A> as TypeEq<Successor<B>>>::Output>;
+}
+
+proofs! {
+    <Zero as LessThanOrEqualAntisymmetric<Zero>>::Proof;
+    <Four as LessThanOrEqualAntisymmetric<Four>>::Proof;
+    <<Two as Sum<Three>>::Output as LessThanOrEqualAntisymmetric<<Three as Sum<Two>>::Output>>::Proof;
+    Equal<<Two as Sum<Three>>::Output, <Three as Sum<Two>>::Output>;
+}
+// It takes both ways round, and `3 ≤ 5` alone isn't enough:
+// proof! { <Three as LessThanOrEqualAntisymmetric<Five>>::Proof }
+// proof! { <Five as LessThanOrEqualAntisymmetric<Three>>::Proof }
+
+// ### Subtraction undoes addition.
+
+type DiffMN<M, N> = <M as Difference<N>>::Output;
+
+/// `(a - b) + b = a`, wherever `a - b` is defined, which is when `a ≥ b`.
+///
+/// By induction on `b`, taking one off `a` along with it:
+/// - `(a - 0) + 0 = a`.
+/// - `((a + 1) - (k + 1)) + (k + 1) = (a - k) + (k + 1) = ((a - k) + k) + 1`, by `SuccessorSum`,
+///   and that's `a + 1` by induction.
+///
+/// There's no impl for `0 - (k + 1)`, so asking for `a < b` doesn't compile.
+pub trait SumUndoesDifference<B: Nat>: Nat + GreaterThanOrEqual<B> {
+    type Proof;
+}
+impl<A: Nat> SumUndoesDifference<Zero> for A {
+    type Proof = Equal<SumMN<DiffMN<A, Zero>, Zero>, A>;
+}
+impl<A: Nat, K: Nat> SumUndoesDifference<Successor<K>> for Successor<A> where
+    A: Difference<K>,
+    DiffMN<A, K>: Nat,
+    // `(a - k) + (k + 1) = ((a - k) + 1) + k = ((a - k) + k) + 1`.
+    K: SuccessorSum<DiffMN<A, K>>,
+    Successor<DiffMN<A, K>>: Sum<K, Output = Successor<SumMN<DiffMN<A, K>, K>>>,
+    // Induction hypothesis: `(a - k) + k = a`.
+    A: SumUndoesDifference<K>,
+    DiffMN<A, K>: Sum<K, Output = A>,
+{
+    type Proof = Equal<SumMN<DiffMN<Successor<A>, Successor<K>>, Successor<K>>, Successor<A>>;
+}
+
+proofs! {
+    <Zero as SumUndoesDifference<Zero>>::Proof;
+    <Five as SumUndoesDifference<Two>>::Proof;
+    <Four as SumUndoesDifference<Four>>::Proof;
+    <Nine as SumUndoesDifference<Zero>>::Proof;
+    <Eight as SumUndoesDifference<Three>>::Proof;
+}
+// Only where the difference is defined:
+// proof! { <Two as SumUndoesDifference<Five>>::Proof }
+// Subtracting zero needs no induction, so that case holds for every `n` as it stands:
+#[crate::attr::proof]
+fn sum_undoes_difference_of_zero<N: Nat>() -> <N as SumUndoesDifference<Zero>>::Proof {}
+
+/// `(a + b) - b = a`, for every `a` and `b`.
+///
+/// By induction on `b`:
+/// - `(a + 0) - 0 = a`.
+/// - `(a + (k + 1)) - (k + 1) = ((a + k) + 1) - (k + 1) = (a + k) - k`, by `SuccessorSum`, and
+///   that's `a` by induction.
+pub trait DifferenceUndoesSum<A: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat> DifferenceUndoesSum<A> for Zero {
+    type Proof = Equal<DiffMN<SumMN<A, Zero>, Zero>, A>;
+}
+impl<A: Nat, K: Nat> DifferenceUndoesSum<A> for Successor<K> where
+    A: Sum<K>,
+    SumMN<A, K>: Nat + Difference<K>,
+    // `a + (k + 1) = (a + 1) + k = (a + k) + 1`.
+    K: SuccessorSum<A>,
+    Successor<A>: Sum<K, Output = Successor<SumMN<A, K>>>,
+    // Induction hypothesis: `(a + k) - k = a`.
+    K: DifferenceUndoesSum<A>,
+    SumMN<A, K>: Difference<K, Output = A>,
+{
+    type Proof = Equal<DiffMN<SumMN<A, Successor<K>>, Successor<K>>, A>;
+}
+
+proofs! {
+    <Zero as DifferenceUndoesSum<Zero>>::Proof;
+    <Three as DifferenceUndoesSum<Two>>::Proof;
+    <Zero as DifferenceUndoesSum<Five>>::Proof;
+}
+
+// ### The difference of two squares.
+
+/// `a² - b² = (a + b)(a - b)`, wherever `a² - b²` is defined, which is when `a ≥ b`.
+///
+/// Write `d = a - b`, so `a = d + b` by `SumUndoesDifference`. Then distributing, with
+/// `ProductMonotone`, and turning products around, with `ProductCommutes`:
+/// - `a² = (d + b)a = da + ba = ad + (d + b)b = ad + (bd + b²)`,
+/// - `(a + b)d = ad + bd`,
+///
+/// and those are `(a + b)d + b²` between them, so taking `b²` away leaves `(a + b)d`.
+///
+/// `SumAssociative` regroups the sum and `DifferenceUndoesSum` takes `b²` back off it. The
+/// lemmas are all proved above, so this holds for every `a ≥ b` outright. There's no `d` when
+/// `a < b`, so asking for that doesn't compile.
+pub trait DifferenceOfSquares<B: Nat>: Nat + GreaterThanOrEqual<B> {
+    type Proof;
+}
+impl<A: Nat, B: Nat> DifferenceOfSquares<B> for A where
+    A: Difference<B>,
+    DiffMN<A, B>: Nat,
+    // `a = d + b`, and the rest is in terms of `d`.
+    A: SumUndoesDifference<B>,
+    DiffMN<A, B>: Sum<B, Output = A>,
+    A: DifferenceOfSquaresBy<B, DiffMN<A, B>>,
+{
+    type Proof = <A as DifferenceOfSquaresBy<B, DiffMN<A, B>>>::Proof;
+}
+// `DifferenceOfSquares`, with `d` given its own name.
+pub trait DifferenceOfSquaresBy<B: Nat, D: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat, B: Nat, D: Nat> DifferenceOfSquaresBy<B, D> for A where
+    // Names for the products and their sums.
+    A: Sum<B> + Product<A> + Product<D>,
+    B: Product<A> + Product<B> + Product<D>,
+    D: Product<A> + Product<B>,
+    SumMN<A, B>: Nat + Product<D>,
+    MulMN<A, A>: Nat + Difference<MulMN<B, B>>,
+    MulMN<A, D>: Nat + Sum<MulMN<B, A>> + Sum<SumMN<MulMN<B, D>, MulMN<B, B>>> + Sum<MulMN<B, D>>,
+    MulMN<B, D>: Nat + Sum<MulMN<B, B>>,
+    MulMN<B, A>: Nat,
+    MulMN<B, B>: Nat,
+    SumMN<MulMN<A, D>, MulMN<B, D>>: Nat,
+    // `a = d + b`.
+    D: Sum<B, Output = A>,
+    // `a² = (d + b)a = da + ba`.
+    A: ProductMonotone<D, B>,
+    A: Product<A, Output = SumMN<MulMN<D, A>, MulMN<B, A>>>,
+    // `da = ad`.
+    A: ProductCommutes<D>,
+    D: Product<A, Output = MulMN<A, D>>,
+    // `ba = ab = (d + b)b = db + b²`, and `db = bd`.
+    A: ProductCommutes<B>,
+    B: Product<A, Output = MulMN<A, B>>,
+    B: ProductMonotone<D, B>,
+    A: Product<B, Output = SumMN<MulMN<D, B>, MulMN<B, B>>>,
+    B: ProductCommutes<D>,
+    D: Product<B, Output = MulMN<B, D>>,
+    // `(a + b)d = ad + bd`.
+    D: ProductMonotone<A, B>,
+    SumMN<A, B>: Product<D, Output = SumMN<MulMN<A, D>, MulMN<B, D>>>,
+    // `(ad + bd) + b² = ad + (bd + b²)`, by `SumAssociative`.
+    MulMN<B, B>: SumAssociative<MulMN<A, D>, MulMN<B, D>>,
+    SumMN<MulMN<A, D>, MulMN<B, D>>: Sum<MulMN<B, B>, Output = SumMN<MulMN<A, D>, SumMN<MulMN<B, D>, MulMN<B, B>>>>,
+    // Taking `b²` back off: `((ad + bd) + b²) - b² = ad + bd`, by `DifferenceUndoesSum`.
+    MulMN<B, B>: DifferenceUndoesSum<SumMN<MulMN<A, D>, MulMN<B, D>>>,
+    SumMN<SumMN<MulMN<A, D>, MulMN<B, D>>, MulMN<B, B>>: Difference<
+        MulMN<B, B>,
+        Output = SumMN<MulMN<A, D>, MulMN<B, D>>,
+    >,
+{
+    type Proof = Equal<
+        <<A as Square>::Output as Difference<<B as Square>::Output>>::Output,
+        MulMN<SumMN<A, B>, D>,
+    >;
+}
+
+proofs! {
+    <Five as DifferenceOfSquares<Three>>::Proof;
+    <Four as DifferenceOfSquares<Four>>::Proof;
+    <Three as DifferenceOfSquares<Zero>>::Proof;
+    <Seven as DifferenceOfSquares<Two>>::Proof;
+    <One as DifferenceOfSquares<Zero>>::Proof;
+    // `6² - 5² = 11 = 11·1`.
+    Equal<<Six as Sum<Five>>::Output, <<Six as Square>::Output as Difference<<Five as Square>::Output>>::Output>;
+    <Six as DifferenceOfSquares<Five>>::Proof;
+}
+// Only where the difference is defined:
+// proof! { <Two as DifferenceOfSquares<Three>>::Proof }
+
+// ### Division with remainder.
+
+type QuotMN<A, D> = <A as Quotient<D>>::Output;
+type RemMN<A, D> = <A as Remainder<D>>::Output;
+// `(a / d)·d + (a mod d)`, which should come back to `a`.
+type DivisionSum<A, D> = SumMN<MulMN<QuotMN<A, D>, D>, RemMN<A, D>>;
+
+/// `(a / d)·d + (a mod d) = a`, for every `a` and every `d ≠ 0`.
+///
+/// `Quotient` and `Remainder` are defined separately, so nothing else makes them agree.
+/// This follows them, by comparing `a` with `d`:
+/// - `a < d`: `0·d + a = a`, by `ZeroProduct` and `ZeroSum`.
+/// - `a = d`: `1·d + 0 = d`, by `OneProduct`.
+/// - `a > d`: with `p = a - d`, the quotient is `p / d + 1` and the remainder is `p mod d`, and
+///   `(p / d + 1)·d + p mod d = ((p / d)·d + p mod d) + d = p + d = a`, by `SuccessorProduct`,
+///   induction on `p`, and `SumUndoesDifference`.
+///
+/// Each case is checked for all `a` and `d` at once, with `SumSwap` rearranging the sum in
+/// the last one. There's no impl for dividing by zero:
+///
+/// ```compile_fail
+/// use sqrt_irrational::*;
+/// proof! { <Four as DivisionIdentity<Zero>>::Proof }
+/// ```
+pub trait DivisionIdentity<D: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat, D: Nat> DivisionIdentity<Successor<D>> for A where
+    A: Compare<Successor<D>>,
+    A: DivisionIdentityStep<Successor<D>, <A as Compare<Successor<D>>>::Output>,
+{
+    type Proof = <A as DivisionIdentityStep<Successor<D>, <A as Compare<Successor<D>>>::Output>>::Proof;
+}
+// One impl per way `a` can compare with `d`.
+pub trait DivisionIdentityStep<D: Nat, Ordering>: Nat {
+    type Proof;
+}
+impl<A: Nat, D: Nat> DivisionIdentityStep<Successor<D>, Less> for A where
+    A: Compare<Successor<D>, Output = Less>,
+    // `0·d = 0`.
+    Successor<D>: ZeroProduct,
+    Zero: Product<Successor<D>, Output = Zero>,
+    // `0 + a = a`.
+    A: ZeroSum,
+    Zero: Sum<A, Output = A>,
+{
+    type Proof = Equal<DivisionSum<A, Successor<D>>, A>;
+}
+// Comparing equal means `a` is `d`, so this only needs an impl for `d` itself.
+impl<D: Nat> DivisionIdentityStep<Successor<D>, EqualOrd> for Successor<D> where
+    Successor<D>: Compare<Successor<D>, Output = EqualOrd>,
+    // `1·d = d`.
+    Successor<D>: OneProduct,
+    One: Product<Successor<D>, Output = Successor<D>>,
+{
+    type Proof = Equal<DivisionSum<Successor<D>, Successor<D>>, Successor<D>>;
+}
+impl<A: Nat, D: Nat> DivisionIdentityStep<Successor<D>, Greater> for A where
+    A: Compare<Successor<D>, Output = Greater>,
+    A: Difference<Successor<D>>,
+    DiffMN<A, Successor<D>>: Nat + Quotient<Successor<D>> + Remainder<Successor<D>>,
+    QuotMN<DiffMN<A, Successor<D>>, Successor<D>>: Product<Successor<D>>,
+    MulMN<QuotMN<DiffMN<A, Successor<D>>, Successor<D>>, Successor<D>>: Nat
+        + Sum<Successor<D>>
+        + Sum<RemMN<DiffMN<A, Successor<D>>, Successor<D>>>,
+    SumMN<MulMN<QuotMN<DiffMN<A, Successor<D>>, Successor<D>>, Successor<D>>, Successor<D>>: Nat,
+    // `(q + 1)d = qd + d`, where `q = p / d`.
+    Successor<D>: SuccessorProduct<QuotMN<DiffMN<A, Successor<D>>, Successor<D>>>,
+    Successor<QuotMN<DiffMN<A, Successor<D>>, Successor<D>>>: Product<
+        Successor<D>,
+        Output = SumMN<MulMN<QuotMN<DiffMN<A, Successor<D>>, Successor<D>>, Successor<D>>, Successor<D>>,
+    >,
+    // `(qd + d) + r = (qd + r) + d`, by `SumSwap`.
+    Successor<D>: Sum<RemMN<DiffMN<A, Successor<D>>, Successor<D>>>,
+    RemMN<DiffMN<A, Successor<D>>, Successor<D>>: Sum<Successor<D>>,
+    MulMN<QuotMN<DiffMN<A, Successor<D>>, Successor<D>>, Successor<D>>: SumSwap<
+        Successor<D>,
+        RemMN<DiffMN<A, Successor<D>>, Successor<D>>,
+    >,
+    SumMN<MulMN<QuotMN<DiffMN<A, Successor<D>>, Successor<D>>, Successor<D>>, Successor<D>>: Sum<
+        RemMN<DiffMN<A, Successor<D>>, Successor<D>>,
+        Output = SumMN<DivisionSum<DiffMN<A, Successor<D>>, Successor<D>>, Successor<D>>,
+    >,
+    // Induction hypothesis: `qd + r = p`.
+    DiffMN<A, Successor<D>>: DivisionIdentity<Successor<D>>,
+    MulMN<QuotMN<DiffMN<A, Successor<D>>, Successor<D>>, Successor<D>>: Sum<
+        RemMN<DiffMN<A, Successor<D>>, Successor<D>>,
+        Output = DiffMN<A, Successor<D>>,
+    >,
+    // `p + d = a`.
+    A: SumUndoesDifference<Successor<D>>,
+    DiffMN<A, Successor<D>>: Sum<Successor<D>, Output = A>,
+{
+    type Proof = Equal<DivisionSum<A, Successor<D>>, A>;
+}
+
+proofs! {
+    <Zero as DivisionIdentity<Three>>::Proof;
+    <Two as DivisionIdentity<Three>>::Proof;
+    <Three as DivisionIdentity<Three>>::Proof;
+    <Seven as DivisionIdentity<Three>>::Proof;
+    <Nine as DivisionIdentity<Three>>::Proof;
+    <Eight as DivisionIdentity<One>>::Proof;
+    <Nine as DivisionIdentity<Four>>::Proof;
+    <<Five as Square>::Output as DivisionIdentity<Seven>>::Proof;
+    Equal<DivisionSum<Seven, Two>, Seven>;
+}
+
+/// `n / 1 = n`, for every `n`.
+///
+/// `0 < 1` and `1 = 1` give `0` and `1` straight off. Past that, `n + 2 > 1`, so
+/// `(n + 2) / 1 = ((n + 2) - 1) / 1 + 1 = (n + 1) / 1 + 1`, and that's `n + 2` by induction.
+pub trait QuotientOne: Nat {
+    type Proof;
+}
+impl QuotientOne for Zero {
+    type Proof = Equal<Zero, QuotMN<Zero, One>>;
+}
+impl QuotientOne for One {
+    type Proof = Equal<One, QuotMN<One, One>>;
+}
+impl<K: Nat> QuotientOne for Successor<Successor<K>> where
+    // Induction hypothesis: `(k + 1) / 1 = k + 1`.
+    Successor<K>: QuotientOne + Quotient<One, Output = Successor<K>>,
+{
+    type Proof = Equal<Successor<Successor<K>>, QuotMN<Successor<Successor<K>>, One>>;
+}
+
+proofs! {
+    <Zero as QuotientOne>::Proof;
+    <One as QuotientOne>::Proof;
+    <Seven as QuotientOne>::Proof;
+}
+
+// ### There are `n` naturals below `n`.
+
+type Len<L> = <L as ListLength>::Output;
+type Below<B> = <Naturals as Enumerate<B>>::Output;
+type MapSucc<L> = <L as MapList<SuccessorFn>>::Output;
+
+/// Mapping over a list doesn't change its length.
+///
+/// By induction on the list: `map f [] = []`, and `map f (h : t) = f h : map f t`, which is one
+/// longer than `map f t`, which by induction is as long as `t`.
+pub trait MapPreservesLength<F>: ListLength {
+    type Proof;
+}
+impl<F> MapPreservesLength<F> for TNil {
+    type Proof = Equal<Len<<TNil as MapList<F>>::Output>, Len<TNil>>;
+}
+impl<F: NatFn<H>, H, T: ListLength> MapPreservesLength<F> for TCons<H, T> where
+    T: MapList<F>,
+    <T as MapList<F>>::Output: ListLength,
+    // Induction hypothesis: `map f t` is as long as `t`.
+    T: MapPreservesLength<F>,
+    <T as MapList<F>>::Output: ListLength<Output = Len<T>>,
+{
+    type Proof = Equal<Len<<TCons<H, T> as MapList<F>>::Output>, Len<TCons<H, T>>>;
+}
+
+/// `Enumerate<B>` lists exactly `b` naturals, so the naturals below any bound are a finite
+/// set, and a search through that list has looked at all of them.
+///
+/// By induction on `b`: nothing is below zero, and below `k + 1` are zero and the `k` naturals
+/// below `k`, each plus one. That's `k + 1` of them by `MapPreservesLength` and induction.
+pub trait EnumerateLength: Nat {
+    type Proof;
+}
+impl EnumerateLength for Zero {
+    type Proof = Equal<Len<Below<Zero>>, Zero>;
+}
+impl<K: Nat> EnumerateLength for Successor<K> where
+    Naturals: Enumerate<K>,
+    Below<K>: ListLength + MapList<SuccessorFn>,
+    MapSucc<Below<K>>: ListLength,
+    // Adding one to each of them leaves `k` of them.
+    Below<K>: MapPreservesLength<SuccessorFn>,
+    MapSucc<Below<K>>: ListLength<Output = Len<Below<K>>>,
+    // Induction hypothesis: there are `k` naturals below `k`.
+    K: EnumerateLength,
+    Below<K>: ListLength<Output = K>,
+{
+    type Proof = Equal<Len<Below<Successor<K>>>, Successor<K>>;
+}
+
+proofs! {
+    <TNil as MapPreservesLength<SquareFn>>::Proof;
+    <RangeList<Two, Six> as MapPreservesLength<SquareFn>>::Proof;
+    <Zero as EnumerateLength>::Proof;
+    <One as EnumerateLength>::Proof;
+    <Six as EnumerateLength>::Proof;
+}
+
+// ### Boolean algebra.
+//
+// There are only two `Bool`s, so these are proved by cases rather than by induction: each
+// law has an impl for `True` and one for `False`, and rustc checks both, for every `b` at
+// once where there's a second variable. The `proofs!` below go through the whole truth table.
+
+type NotOf<A> = <A as Not>::Output;
+type AndOf<A, B> = <A as And<B>>::Output;
+type OrOf<A, B> = <A as Or<B>>::Output;
+
+/// `a ∧ a = a` and `a ∨ a = a`.
+pub trait Idempotence: Bool {
+    type And;
+    type Or;
+}
+impl Idempotence for True {
+    type And = Equal<AndOf<True, True>, True>;
+    type Or = Equal<OrOf<True, True>, True>;
+}
+impl Idempotence for False {
+    type And = Equal<AndOf<False, False>, False>;
+    type Or = Equal<OrOf<False, False>, False>;
+}
+
+/// `a ∨ (a ∧ b) = a` and `a ∧ (a ∨ b) = a`.
+pub trait Absorption<B: Bool>: Bool {
+    type OrAnd;
+    type AndOr;
+}
+impl<B: Bool> Absorption<B> for True {
+    type OrAnd = Equal<OrOf<True, AndOf<True, B>>, True>;
+    type AndOr = Equal<AndOf<True, OrOf<True, B>>, True>;
+}
+impl<B: Bool> Absorption<B> for False {
+    type OrAnd = Equal<OrOf<False, AndOf<False, B>>, False>;
+    type AndOr = Equal<AndOf<False, OrOf<False, B>>, False>;
+}
+
+/// `¬(a ∧ b) = ¬a ∨ ¬b` and `¬(a ∨ b) = ¬a ∧ ¬b`.
+pub trait DeMorgan<B: Bool>: Bool {
+    type NotAnd;
+    type NotOr;
+}
+impl<B: Bool + Not> DeMorgan<B> for True {
+    // `¬(1 ∧ b) = ¬b = 0 ∨ ¬b`, and `¬(1 ∨ b) = 0 = 0 ∧ ¬b`.
+    type NotAnd = Equal<NotOf<AndOf<True, B>>, OrOf<NotOf<True>, NotOf<B>>>;
+    type NotOr = Equal<NotOf<OrOf<True, B>>, AndOf<NotOf<True>, NotOf<B>>>;
+}
+impl<B: Bool + Not> DeMorgan<B> for False {
+    // `¬(0 ∧ b) = 1 = 1 ∨ ¬b`, and `¬(0 ∨ b) = ¬b = 1 ∧ ¬b`.
+    type NotAnd = Equal<NotOf<AndOf<False, B>>, OrOf<NotOf<False>, NotOf<B>>>;
+    type NotOr = Equal<NotOf<OrOf<False, B>>, AndOf<NotOf<False>, NotOf<B>>>;
+}
+
+/// `¬¬a = a`.
+pub trait DoubleNegation: Bool {
+    type Proof;
+}
+impl DoubleNegation for True {
+    type Proof = Equal<NotOf<NotOf<True>>, True>;
+}
+impl DoubleNegation for False {
+    type Proof = Equal<NotOf<NotOf<False>>, False>;
+}
+
+proofs! {
+    <True as Idempotence>::And;
+    <True as Idempotence>::Or;
+    <False as Idempotence>::And;
+    <False as Idempotence>::Or;
+
+    <True as Absorption<True>>::OrAnd;
+    <True as Absorption<False>>::OrAnd;
+    <False as Absorption<True>>::OrAnd;
+    <False as Absorption<False>>::OrAnd;
+    <True as Absorption<True>>::AndOr;
+    <True as Absorption<False>>::AndOr;
+    <False as Absorption<True>>::AndOr;
+    <False as Absorption<False>>::AndOr;
+
+    <True as DeMorgan<True>>::NotAnd;
+    <True as DeMorgan<False>>::NotAnd;
+    <False as DeMorgan<True>>::NotAnd;
+    <False as DeMorgan<False>>::NotAnd;
+    <True as DeMorgan<True>>::NotOr;
+    <True as DeMorgan<False>>::NotOr;
+    <False as DeMorgan<True>>::NotOr;
+    <False as DeMorgan<False>>::NotOr;
+
+    <True as DoubleNegation>::Proof;
+    <False as DoubleNegation>::Proof;
+
+    // `TypeEq` agrees, and says so as a `Bool`:
+    Equal<True, <NotOf<NotOf<False>> as TypeEq<False>>::Output>;
+    Equal<True, <NotOf<AndOf<True, False>> as TypeEq<OrOf<NotOf<True>, NotOf<False>>>>::Output>;
+    Equal<False, <NotOf<AndOf<True, False>> as TypeEq<AndOf<NotOf<True>, NotOf<False>>>>::Output>;
+}
+// Distributing `¬` without flipping `∧` to `∨` doesn't work:
+// proof! { Equal<NotOf<AndOf<True, False>>, AndOf<NotOf<True>, NotOf<False>>> }
+
+// ### Triangle numbers are squeezed between `n` and `n²`.
+
+// Concretely, for the first few `n`:
+proofs! {
+    <One as LessThanOrEqual<<One as Triangular>::Output>>::Output;
+    <<One as Triangular>::Output as LessThanOrEqual<<One as Square>::Output>>::Output;
+    <Two as LessThanOrEqual<<Two as Triangular>::Output>>::Output;
+    <<Two as Triangular>::Output as LessThanOrEqual<<Two as Square>::Output>>::Output;
+    <Three as LessThanOrEqual<<Three as Triangular>::Output>>::Output;
+    <<Three as Triangular>::Output as LessThanOrEqual<<Three as Square>::Output>>::Output;
+    <Five as LessThanOrEqual<<Five as Triangular>::Output>>::Output;
+    <<Five as Triangular>::Output as LessThanOrEqual<<Five as Square>::Output>>::Output;
+}
+// But they're not below `n` past the start:
+// proof! { <<Three as Triangular>::Output as LessThanOrEqual<Three>>::Output }
+
+type Tri<N> = <N as Triangular>::Output;
+
+/// `(m + 1)² = T(m + 1) + T(m)`: a square splits into two triangles, either side of its diagonal.
+///
+/// By induction on `m`, writing `n = m + 1`:
+/// - `1² = 1 + 0`.
+/// - `(n + 1)² = (n² + n) + (n + 1) = ((T(n) + T(m)) + n) + (n + 1)` by induction, and
+///   shuffling that around gives `(T(n) + (n + 1)) + (T(m) + n) = T(n + 1) + T(n)`.
+///
+/// Expanding `(n + 1)²` is `SuccessorProduct` and the shuffle is `SumShuffle`, both proved above,
+/// so this holds for every `m` outright.
+pub trait SquareSplits: Nat {
+    type Proof;
+}
+impl SquareSplits for Zero {
+    type Proof = Equal<<One as Square>::Output, <Tri<One> as Sum<Tri<Zero>>>::Output>;
+}
+impl<M: Nat> SquareSplits for Successor<M> where
+    M: Triangular,
+    Successor<M>: Triangular + Product<Successor<M>>,
+    Successor<Successor<M>>: Triangular,
+    Tri<Successor<M>>: Sum<Tri<M>>,
+    <Tri<Successor<M>> as Sum<Tri<M>>>::Output: Nat + Sum<Successor<M>>,
+    <<Tri<Successor<M>> as Sum<Tri<M>>>::Output as Sum<Successor<M>>>::Output: Nat,
+    MulMN<Successor<M>, Successor<M>>: Nat + Sum<Successor<M>>,
+    <MulMN<Successor<M>, Successor<M>> as Sum<Successor<M>>>::Output: Nat + Sum<Successor<Successor<M>>>,
+    Tri<Successor<Successor<M>>>: Sum<Tri<Successor<M>>>,
+    Tri<M>: Sum<Successor<M>>,
+    SumMN<Tri<M>, Successor<M>>: Nat + Sum<Successor<Successor<M>>>,
+    // Induction hypothesis: `n² = T(n) + T(m)`.
+    M: SquareSplits,
+    Successor<M>: Product<Successor<M>, Output = <Tri<Successor<M>> as Sum<Tri<M>>>::Output>,
+    // `(n + 1)² = (n + 1)n + (n + 1) = (n² + n) + (n + 1)`, by `SuccessorProduct`.
+    Successor<M>: SuccessorProduct<Successor<M>>,
+    Successor<Successor<M>>: Product<Successor<M>, Output = <MulMN<Successor<M>, Successor<M>> as Sum<Successor<M>>>::Output>,
+    Successor<Successor<M>>: Product<
+        Successor<Successor<M>>,
+        Output = <<MulMN<Successor<M>, Successor<M>> as Sum<Successor<M>>>::Output as Sum<Successor<Successor<M>>>>::Output,
+    >,
+    // `((T(n) + T(m)) + n) + (n + 1) = (T(n) + (n + 1)) + (T(m) + n)`, by `SumShuffle`, and
+    // that's `T(n + 1) + T(n)`.
+    Tri<Successor<M>>: SumShuffle<Tri<M>, Successor<M>, Successor<Successor<M>>>,
+    <<Tri<Successor<M>> as Sum<Tri<M>>>::Output as Sum<Successor<M>>>::Output: Sum<
+        Successor<Successor<M>>,
+        Output = <Tri<Successor<Successor<M>>> as Sum<Tri<Successor<M>>>>::Output,
+    >,
+{
+    type Proof = Equal<
+        <Successor<Successor<M>> as Square>::Output,
+        <Tri<Successor<Successor<M>>> as Sum<Tri<Successor<M>>>>::Output,
+    >;
+}
+
+/// `n ≤ T(n) ≤ n²` for every `n ≥ 1`.
+///
+/// `T(n) = T(n - 1) + n`, and `SquareSplits` gives `n² = T(n) + T(n - 1)`, so both
+/// differences are `T(n - 1)`. That subtracting undoes adding is checked at each
+/// concrete `n`; the rest holds for all `n` at once.
+pub trait TriangularBounds: Nat {
+    /// `n ≤ T(n)`.
+    type Lower;
+    /// `T(n) ≤ n²`.
+    type Upper;
+}
+impl<M: Nat> TriangularBounds for Successor<M> where
+    M: Triangular,
+    Successor<M>: Triangular + Product<Successor<M>>,
+    Tri<Successor<M>>: Sum<Tri<M>>,
+    <Tri<Successor<M>> as Sum<Tri<M>>>::Output: Nat,
+    // `n² = T(n) + T(n - 1)`.
+    M: SquareSplits,
+    Successor<M>: Product<Successor<M>, Output = <Tri<Successor<M>> as Sum<Tri<M>>>::Output>,
+    // `(T(n) + T(n - 1)) - T(n) = T(n - 1)`.
+    <Tri<Successor<M>> as Sum<Tri<M>>>::Output: Difference<Tri<Successor<M>>, Output = Tri<M>>,
+    // `(T(n - 1) + n) - n = T(n - 1)`.
+    Tri<Successor<M>>: Difference<Successor<M>, Output = Tri<M>>,
+{
+    type Lower = <Successor<M> as LessThanOrEqual<Tri<Successor<M>>>>::Output;
+    type Upper = <Tri<Successor<M>> as LessThanOrEqual<<Successor<M> as Square>::Output>>::Output;
+}
+
+proofs! {
+    <Zero as SquareSplits>::Proof;
+    <Two as SquareSplits>::Proof;
+    <Four as SquareSplits>::Proof;
+    <One as TriangularBounds>::Lower;
+    <One as TriangularBounds>::Upper;
+    <Four as TriangularBounds>::Lower;
+    <Four as TriangularBounds>::Upper;
+    <Six as TriangularBounds>::Upper;
+}
+
+// ### Factorials outgrow powers of two.
+
+type Fact<N> = <N as Factorial>::Output;
+type PowTwo<N> = <Two as Power<N>>::Output;
+type Slack<M> = <M as FactorialBound>::Slack;
+// `f = 2ᵐ + s`, which is `(m + 1)!`.
+type FactOf<M> = SumMN<PowTwo<M>, Slack<M>>;
+// `2s + f·m`.
+type NextSlack<M> = SumMN<MulMN<Slack<M>, Two>, MulMN<FactOf<M>, M>>;
+
+/// `(m + 1)! = 2ᵐ + s` for some natural `s`, which is `Slack`. That's `n! ≥ 2ⁿ⁻¹` for `n = m + 1 ≥ 1`.
+///
+/// By induction on `m`:
+/// - `1! = 1 = 2⁰ + 0`.
+/// - `(m + 2)! = (m + 1)!·(m + 2) = (m + 1)!·2 + (m + 1)!·m`. By induction the first
+///   part is `(2ᵐ + s)·2 = 2ᵐ⁺¹ + 2s`, so the new slack is `2s + (m + 1)!·m`.
+///   This is where `m + 2 ≥ 2` gets used, in splitting a `2` off.
+///
+/// Distributing `f` is `ProductDistributes`, distributing `p + s` is `ProductMonotone`, and
+/// moving the brackets is `SumAssociative`. They're all proved above, so this holds for every `m`
+/// outright.
+pub trait FactorialBound: Nat {
+    type Slack: Nat;
+    type Proof;
+}
+impl FactorialBound for Zero {
+    type Slack = Zero;
+    type Proof = Equal<Fact<One>, SumMN<PowTwo<Zero>, Zero>>;
+}
+impl<M: Nat> FactorialBound for Successor<M> where
+    // Names for `p = 2ᵐ`, `s`, `f = p + s`, and the pieces of the step.
+    Two: Power<M>,
+    M: FactorialBound,
+    PowTwo<M>: Nat + Sum<Slack<M>> + Product<Two>,
+    Slack<M>: Product<Two>,
+    FactOf<M>: Nat + Product<M> + Product<Two> + Product<Successor<Successor<M>>>,
+    MulMN<PowTwo<M>, Two>: Nat + Sum<MulMN<Slack<M>, Two>> + Sum<NextSlack<M>>,
+    MulMN<Slack<M>, Two>: Nat + Sum<MulMN<FactOf<M>, M>>,
+    MulMN<FactOf<M>, Two>: Sum<MulMN<FactOf<M>, M>>,
+    MulMN<FactOf<M>, M>: Nat,
+    NextSlack<M>: Nat,
+    SumMN<MulMN<PowTwo<M>, Two>, NextSlack<M>>: Nat,
+    // Induction hypothesis: `(m + 1)! = f`.
+    Successor<M>: Factorial<Output = FactOf<M>>,
+    // `f(2 + m) = f·2 + f·m`, by `ProductDistributes`, and `2 + m = m + 2` by `SumCommutes`.
+    M: SumCommutes<Two>,
+    Two: Sum<M, Output = Successor<Successor<M>>>,
+    M: ProductDistributes<FactOf<M>, Two>,
+    FactOf<M>: Product<Successor<Successor<M>>, Output = SumMN<MulMN<FactOf<M>, Two>, MulMN<FactOf<M>, M>>>,
+    // `(p + s)·2 = p·2 + s·2`, by `ProductMonotone`.
+    Two: ProductMonotone<PowTwo<M>, Slack<M>>,
+    FactOf<M>: Product<Two, Output = SumMN<MulMN<PowTwo<M>, Two>, MulMN<Slack<M>, Two>>>,
+    // `(p·2 + s·2) + f·m = p·2 + (s·2 + f·m)`, by `SumAssociative`.
+    MulMN<FactOf<M>, M>: SumAssociative<MulMN<PowTwo<M>, Two>, MulMN<Slack<M>, Two>>,
+    SumMN<MulMN<PowTwo<M>, Two>, MulMN<Slack<M>, Two>>: Sum<
+        MulMN<FactOf<M>, M>,
+        Output = SumMN<MulMN<PowTwo<M>, Two>, NextSlack<M>>,
+    >,
+{
+    type Slack = NextSlack<M>;
+    type Proof = Equal<Fact<Successor<Successor<M>>>, SumMN<PowTwo<Successor<M>>, NextSlack<M>>>;
+}
+
+proofs! {
+    <Zero as FactorialBound>::Proof;
+    <One as FactorialBound>::Proof;
+    <Two as FactorialBound>::Proof;
+    <Three as FactorialBound>::Proof;
+    // `4! = 24 = 8 + 16`.
+    Equal<<Eight as Product<Two>>::Output, <Three as FactorialBound>::Slack>;
+    // And as inequalities, up to where the numbers get too deep:
+    <Fact<One> as GreaterThanOrEqual<PowTwo<Zero>>>::Output;
+    <Fact<Two> as GreaterThanOrEqual<PowTwo<One>>>::Output;
+    <Fact<Three> as GreaterThanOrEqual<PowTwo<Two>>>::Output;
+    <Fact<Four> as GreaterThanOrEqual<PowTwo<Three>>>::Output;
+}
+// It's tight at the start, and no better than that:
+// proof! { <Fact<Two> as GreaterThanOrEqual<PowTwo<Two>>>::Output }
+// proof! { <Fact<Three> as GreaterThanOrEqual<PowTwo<Three>>>::Output }
+
+// ### Wilson's theorem, for small primes.
+//
+// `(p - 1)! ≡ -1 (mod p)` exactly when `p > 1` is prime. The general proof pairs each residue
+// with its inverse, which needs the whole multiplicative group mod `p` at once, so it's checked here one
+// prime at a time instead. That doesn't go far: `6! = 720`, and a Peano natural that big is
+// far past how deep rustc will go, so `p = 5` is the largest prime this can reach.
+
+// `(p - 1)!` and `-1` leave the same remainder mod `p`.
+type WilsonStatement<P> = Equal<
+    <Pos<Fact<<P as Difference<One>>::Output>> as IntRemainder<P>>::Output,
+    <Neg<One> as IntRemainder<P>>::Output,
+>;
+// The same thing as `p | (p - 1)! + 1`.
+type WilsonDivides<P> = <P as Divides<Successor<Fact<<P as Difference<One>>::Output>>>>::Output;
+
+proofs! {
+    WilsonStatement<Two>;
+    WilsonStatement<Three>;
+    // `4! = 24 ≡ 4 ≡ -1 (mod 5)`.
+    WilsonStatement<Five>;
+    Equal<Four, <Neg<One> as IntRemainder<Five>>::Output>;
+    WilsonDivides<Two>;
+    WilsonDivides<Three>;
+    WilsonDivides<Five>;
+}
+// It doesn't hold for composites, like `4`:
+// proof! { WilsonStatement<Four> }
+// And `p = 7` is out of reach:
+// proof! { WilsonStatement<Seven> }
+
+// By value, for the same primes and a few more.
+const fn wilson_holds(p: u64) -> bool {
+    let mut factorial = 1;
+    let mut k = 2;
+    while k < p {
+        factorial = factorial * k % p;
+        k += 1;
+    }
+    (factorial + 1) % p == 0
+}
+const _: () = assert!(<Fact<Four> as Reify>::OUTPUT % 5 == 5 - 1);
+const _: () = assert!(<<Pos<Fact<Four>> as IntRemainder<Five>>::Output as Reify>::OUTPUT == 4);
+const _: () = assert!(wilson_holds(2) && wilson_holds(3) && wilson_holds(5) && wilson_holds(7) && wilson_holds(13));
+const _: () = assert!(!wilson_holds(4) && !wilson_holds(6) && !wilson_holds(9));
+
+// ### Coprime means a Bézout combination makes one.
+
+/// If `gcd(a, b) = 1`, then `ExtGcd` finds `x` and `y` with `ax + by = 1`.
+///
+/// `ExtGcd` isn't proved correct in general: that it finds the same gcd as `Gcd`, and that its
+/// coefficients really combine to it, only get checked for the pairs the `Proof` is used at.
+/// From those and coprimality, rustc works out the rest for all `a` and `b`.
+pub trait CoprimeBezout<B> {
+    type Proof;
+}
+impl<A: Nat, B: Nat> CoprimeBezout<B> for A where
+    // Hypothesis: `gcd(a, b) = 1`.
+    A: Coprime<B> + Gcd<B, Output = One>,
+    // `ExtGcd` agrees with `Gcd`,
+    A: ExtGcd<B, Gcd = <A as Gcd<B>>::Output>,
+    // and `ax + by = gcd(a, b)`.
+    A: BezoutSum<B, <A as ExtGcd<B>>::X, <A as ExtGcd<B>>::Y, Output = Pos<<A as ExtGcd<B>>::Gcd>>,
+{
+    type Proof = Equal<Pos<One>, Bezout<A, B, <A as ExtGcd<B>>::X, <A as ExtGcd<B>>::Y>>;
+}
+
+/// If `ax + by = 1` for some integers `x` and `y`, then `gcd(a, b)` divides one,
+/// which is to say `a` and `b` are coprime.
+///
+/// `gcd(a, b)` divides `a` and `b` (that's `GcdDivides`), and so it divides `ax + by`.
+/// There's no lemma for that last step, so it's checked wherever `a`, `b`, `x` and `y` are
+/// concrete; rustc does the rest.
+pub trait BezoutCoprime<B, X, Y> {
+    type Proof;
+}
+impl<A: Nat, B: Nat, X: Int, Y: Int> BezoutCoprime<B, X, Y> for A where
+    // Hypothesis: `ax + by = 1`.
+    A: BezoutSum<B, X, Y, Output = Pos<One>>,
+    // A common divisor of `a` and `b` divides `|ax + by|`.
+    A: Gcd<B> + GcdDivides<B>,
+    <A as Gcd<B>>::Output: Divides<<Bezout<A, B, X, Y> as IntAbs>::Output>,
+{
+    type Proof = <<A as Gcd<B>>::Output as Divides<One>>::Output;
+}
+
+proofs! {
+    <Five as CoprimeBezout<Three>>::Proof;
+    <Nine as CoprimeBezout<Four>>::Proof;
+    <Five as BezoutCoprime<Three, Pos<Two>, Neg<Three>>>::Proof;
+    <Four as BezoutCoprime<Seven, Pos<Two>, Neg<One>>>::Proof;
+    <Four as Coprime<Seven>>::Output;
+}
+// `gcd(4, 6) = 2`, so neither direction gets off the ground:
+// proof! { <Four as CoprimeBezout<Six>>::Proof }
+// proof! { <Four as BezoutCoprime<Six, Pos<Two>, Neg<One>>>::Proof }
+
+/// A common divisor `d` of `a` and `b` divides `gcd(a, b)`, given `x` and `y` with
+/// `ax + by = gcd(a, b)`. `ExtGcd` finds those, as in `CoprimeBezout`.
+///
+/// `d` divides `ax + by`, since it divides `a` and `b`. As in `BezoutCoprime`, there's no lemma
+/// for that step, so it's checked wherever `a`, `b`, `x`, `y` and `d` are concrete. rustc does
+/// the rest.
+pub trait CommonDivisorDividesGcd<A, B, X, Y> {
+    type Proof;
+}
+impl<A: Nat, B: Nat, X: Int, Y: Int, D: Nat> CommonDivisorDividesGcd<A, B, X, Y> for D where
+    // Hypotheses: `d | a`, `d | b` and `ax + by = gcd(a, b)`.
+    D: Divides<A> + Divides<B>,
+    A: Gcd<B> + BezoutSum<B, X, Y, Output = Pos<GcdMN<A, B>>>,
+    // `d` divides `|ax + by|`.
+    D: Divides<<Bezout<A, B, X, Y> as IntAbs>::Output>,
+{
+    type Proof = <D as Divides<GcdMN<A, B>>>::Output;
+}
+
+proofs! {
+    // `4·(-1) + 6·1 = 2`, and `9·1 + 6·(-1) = 3`.
+    <Two as CommonDivisorDividesGcd<Four, Six, Neg<One>, Pos<One>>>::Proof;
+    <Three as CommonDivisorDividesGcd<Nine, Six, Pos<One>, Neg<One>>>::Proof;
+    <One as CommonDivisorDividesGcd<Zero, Five, Pos<Zero>, Pos<One>>>::Proof;
+}
+// It takes both hypotheses, and `2` divides `4` but not `9`:
+// proof! { <Two as CommonDivisorDividesGcd<Four, Nine, Neg<Two>, Pos<One>>>::Proof }
+
+// ### Every natural is even or odd, and not both.
+
+/// Being able to write this type is proof that `N` is even.
+pub struct EvenWitness<N: Even> { _n: PhantomData<N> }
+/// Being able to write this type is proof that `N` is odd.
+pub struct OddWitness<N: Odd> { _n: PhantomData<N> }
+
+/// A proof that `N` is `Even` or that it's `Odd`, which knows how to become
+/// a proof of the opposite for `N + 1`.
+pub trait ParityWitness<N: Nat> {
+    type IsEven: Bool;
+    type Next: ParityWitness<Successor<N>>;
+}
+impl<N: Even> ParityWitness<N> for EvenWitness<N> {
+    type IsEven = True;
+    type Next = OddWitness<Successor<N>>;
+}
+impl<N: Odd> ParityWitness<N> for OddWitness<N> {
+    type IsEven = False;
+    type Next = EvenWitness<Successor<N>>;
+}
+
+/// Every natural is `Even` or `Odd`, by induction: `0` is even, and whichever one `n` is,
+/// `n + 1` is the other. This needs nothing beyond the induction hypothesis, so rustc
+/// checks it for all naturals at once.
+///
+/// None are both, either. `0` isn't odd, and `n + 1` can only be both if `n` is.
+pub trait ParityTotal: Nat + Sized {
+    type Proof: ParityWitness<Self>;
+}
+impl ParityTotal for Zero {
+    type Proof = EvenWitness<Zero>;
+}
+impl<N: ParityTotal> ParityTotal for Successor<N> {
+    type Proof = <<N as ParityTotal>::Proof as ParityWitness<N>>::Next;
+}
+
+proofs! {
+    Equal<EvenWitness<Zero>, <Zero as ParityTotal>::Proof>;
+    Equal<OddWitness<Seven>, <Seven as ParityTotal>::Proof>;
+    Equal<EvenWitness<Eight>, <Eight as ParityTotal>::Proof>;
+    // It agrees with `Parity`:
+    Equal<IsEven<Seven>, <<Seven as ParityTotal>::Proof as ParityWitness<Seven>>::IsEven>;
+    Equal<IsEven<Eight>, <<Eight as ParityTotal>::Proof as ParityWitness<Eight>>::IsEven>;
+}
+// And there's no getting it the wrong way around:
+// proof! { EvenWitness<Three> }
+// proof! { OddWitness<Zero> }
+// proof! { Equal<OddWitness<Eight>, <Eight as ParityTotal>::Proof> }
+
+/// `2h` is even, for every `h`: `2·0 = 0`, and `2(h + 1) = 2h + 2`, which is how `Product`
+/// unfolds, so rustc checks this for all naturals at once.
+pub trait DoubleEven: Nat {
+    type Proof;
+}
+impl DoubleEven for Zero {
+    type Proof = EvenWitness<MulMN<Two, Zero>>;
+}
+impl<H: Nat> DoubleEven for Successor<H> where
+    Two: Product<H>,
+    MulMN<Two, H>: Nat,
+    // Induction hypothesis: `2h` is even.
+    H: DoubleEven,
+    MulMN<Two, H>: Even,
+{
+    type Proof = EvenWitness<MulMN<Two, Successor<H>>>;
+}
+
+/// If `p` is odd, so is `(p + a) + a`, by induction on `a`:
+/// - `(p + 0) + 0 = p`.
+/// - `(p + (k + 1)) + (k + 1) = ((p + k) + k) + 2`, by `SuccessorSum` three times,
+///   and that's odd by induction.
+pub trait OddPlusTwice<P: Odd>: Nat {
+    type Proof;
+}
+impl<P: Odd> OddPlusTwice<P> for Zero {
+    type Proof = OddWitness<SumMN<SumMN<P, Zero>, Zero>>;
+}
+impl<P: Odd, K: Nat> OddPlusTwice<P> for Successor<K> where
+    P: Sum<K>,
+    SumMN<P, K>: Nat + Sum<K>,
+    SumMN<SumMN<P, K>, K>: Nat,
+    // `p + (k + 1) = (p + 1) + k = (p + k) + 1`.
+    K: SuccessorSum<P>,
+    Successor<P>: Sum<K, Output = Successor<SumMN<P, K>>>,
+    // `((p + k) + 1) + (k + 1) = ((p + k) + 2) + k = (((p + k) + k) + 1) + 1`.
+    K: SuccessorSum<Successor<SumMN<P, K>>>,
+    Successor<Successor<SumMN<P, K>>>: Sum<K, Output = Successor<SumMN<Successor<SumMN<P, K>>, K>>>,
+    K: SuccessorSum<SumMN<P, K>>,
+    Successor<SumMN<P, K>>: Sum<K, Output = Successor<SumMN<SumMN<P, K>, K>>>,
+    // Induction hypothesis: `(p + k) + k` is odd.
+    K: OddPlusTwice<P>,
+    SumMN<SumMN<P, K>, K>: Odd,
+{
+    type Proof = OddWitness<SumMN<SumMN<P, Successor<K>>, Successor<K>>>;
+}
+
+/// A product of odd numbers is odd, by induction over the odd `b`:
+/// - `a·1 = 0 + a = a`, by `ZeroSum`.
+/// - `a(k + 2) = (ak + a) + a`, which is odd by `OddPlusTwice`, since `ak` is by induction.
+pub trait OddProduct<A: Odd>: Odd {
+    type Proof;
+}
+impl<A: Odd> OddProduct<A> for One where
+    A: ZeroSum,
+    Zero: Sum<A, Output = A>,
+{
+    type Proof = OddWitness<MulMN<A, One>>;
+}
+impl<A: Odd, K: Odd> OddProduct<A> for Successor<Successor<K>> where
+    A: Product<K>,
+    MulMN<A, K>: Nat + Sum<A>,
+    SumMN<MulMN<A, K>, A>: Nat + Sum<A>,
+    // Induction hypothesis: `ak` is odd.
+    K: OddProduct<A>,
+    MulMN<A, K>: Odd,
+    // So `(ak + a) + a` is.
+    A: OddPlusTwice<MulMN<A, K>>,
+    SumMN<SumMN<MulMN<A, K>, A>, A>: Odd,
+{
+    type Proof = OddWitness<MulMN<A, Successor<Successor<K>>>>;
+}
+
+/// If `a² = 2h`, then `a` is even.
+///
+/// `ParityTotal` says `a` is even or odd. If it's even, that's the `Proof`. If it's odd, then
+/// `a²` is odd by `OddProduct`, and even by `DoubleEven`, and the `Proof` is both witnesses
+/// at once. No natural is both, so at any `a` that type can't be written, and anything that
+/// needs `a` even can ask for `Proof = EvenWitness<A>`. That last step, that nothing is both,
+/// isn't something a bound can say, so it's the one rustc takes on trust here.
+pub trait EvenSquareRoot<H>: Nat {
+    type Proof;
+}
+impl<A: ParityTotal, H> EvenSquareRoot<H> for A where
+    A: EvenSquareRootStep<H, <A as ParityTotal>::Proof>,
+{
+    type Proof = <A as EvenSquareRootStep<H, <A as ParityTotal>::Proof>>::Proof;
+}
+pub trait EvenSquareRootStep<H, Parity>: Nat {
+    type Proof;
+}
+impl<A: Even, H> EvenSquareRootStep<H, EvenWitness<A>> for A {
+    type Proof = EvenWitness<A>;
+}
+impl<A: Odd, H: Nat> EvenSquareRootStep<H, OddWitness<A>> for A where
+    A: Product<A>,
+    MulMN<A, A>: Nat,
+    // Hypothesis: `a² = 2h`, and `2h` is even.
+    Two: Product<H, Output = MulMN<A, A>>,
+    H: DoubleEven,
+    MulMN<A, A>: Even,
+    // `a` is odd, so `a²` is.
+    A: OddProduct<A>,
+    MulMN<A, A>: Odd,
+{
+    type Proof = (EvenWitness<MulMN<A, A>>, OddWitness<MulMN<A, A>>);
+}
+
+proofs! {
+    <Zero as DoubleEven>::Proof;
+    <Four as DoubleEven>::Proof;
+    <Three as OddPlusTwice<Five>>::Proof;
+    <Seven as OddProduct<Three>>::Proof;
+    <One as OddProduct<Nine>>::Proof;
+    // `6² = 36 = 2·18`.
+    Equal<EvenWitness<Six>, <Six as EvenSquareRoot<<Three as Product<Six>>::Output>>::Proof>;
+    Equal<EvenWitness<Zero>, <Zero as EvenSquareRoot<Zero>>::Proof>;
+}
+// An odd `a` gets nowhere, since `a²` would have to be even. `3² = 9` isn't `2·4`:
+// proof! { <Three as EvenSquareRoot<Four>>::Proof }
+
+// ### A product of evens is a multiple of four.
+
+type HalfOf<N> = <N as AsDouble>::Half;
+
+/// Every `Even` natural is `2k` for some `k`, which is `Half`.
+///
+/// By induction over the evens: `0 = 2·0`, and if `m = 2k`, then `m + 2 = 2k + 2 = 2(k + 1)`.
+/// That last step is how `Product` unfolds, so rustc checks this for every even at once.
+pub trait AsDouble: Even {
+    type Half: Nat;
+    type Proof;
+}
+impl AsDouble for Zero {
+    type Half = Zero;
+    type Proof = Equal<MulMN<Two, Zero>, Zero>;
+}
+impl<M: AsDouble> AsDouble for Successor<Successor<M>> where
+    // Induction hypothesis: `m = 2k`.
+    Two: Product<HalfOf<M>, Output = M>,
+{
+    type Half = Successor<HalfOf<M>>;
+    type Proof = Equal<MulMN<Two, Successor<HalfOf<M>>>, Successor<Successor<M>>>;
+}
+
+/// Two divides `2k`, for every `k`.
+///
+/// `Divides` takes a remainder, which counts down by twos, so this goes by induction two at a
+/// time: `0` and `2` leave nothing over, and `2(k + 2) = 2(k + 1) + 2` leaves what `2(k + 1)`
+/// does, which is nothing by induction.
+pub trait TwoDividesDouble: Nat {
+    type Proof;
+}
+impl TwoDividesDouble for Zero {
+    type Proof = <Two as Divides<MulMN<Two, Zero>>>::Output;
+}
+impl TwoDividesDouble for One {
+    type Proof = <Two as Divides<MulMN<Two, One>>>::Output;
+}
+impl<K: Nat> TwoDividesDouble for Successor<Successor<K>> where
+    Two: Product<K>,
+    MulMN<Two, K>: Nat,
+    // Induction hypothesis: `2(k + 1)` leaves no remainder.
+    Successor<K>: TwoDividesDouble,
+    MulMN<Two, Successor<K>>: Remainder<Two, Output = Zero>,
+{
+    type Proof = <Two as Divides<MulMN<Two, Successor<Successor<K>>>>>::Output;
+}
+
+proofs! {
+    <Zero as TwoDividesDouble>::Proof;
+    <One as TwoDividesDouble>::Proof;
+    <Five as TwoDividesDouble>::Proof;
+}
+
+/// If `a` and `b` are even, then four divides `ab`.
+///
+/// `AsDouble` gives `a = 2h` and `b = 2k`, and then `ab = (2h)(2k) = 4hk`, so `Cofactor` is `hk`.
+/// The multiple of four is written `(hk + hk) + (hk + hk)`, which rustc can carry around without
+/// trying to unfold it. Turning `2h` around to `h·2`, and shuffling the factors, are checked at
+/// each concrete `a` and `b` the `Proof` is used at. `Divides` computes a remainder, so it
+/// gets checked there too.
+pub trait FourDividesProduct<B> {
+    type Cofactor;
+    type Proof;
+    type Divides;
+}
+impl<A: AsDouble, B: AsDouble> FourDividesProduct<B> for A where
+    A: Product<B>,
+    HalfOf<A>: Product<HalfOf<B>>,
+    MulMN<HalfOf<A>, HalfOf<B>>: Nat + Sum<MulMN<HalfOf<A>, HalfOf<B>>>,
+    SumMN<MulMN<HalfOf<A>, HalfOf<B>>, MulMN<HalfOf<A>, HalfOf<B>>>: Nat + Sum<SumMN<MulMN<HalfOf<A>, HalfOf<B>>, MulMN<HalfOf<A>, HalfOf<B>>>>,
+    // `a = h·2` and `b = k·2`,
+    HalfOf<A>: Product<Two, Output = A>,
+    HalfOf<B>: Product<Two, Output = B>,
+    // and `(h·2)(k·2) = 4hk`.
+    MulMN<HalfOf<A>, Two>: Product<MulMN<HalfOf<B>, Two>, Output = Quadruple<MulMN<HalfOf<A>, HalfOf<B>>>>,
+    // A multiple of four leaves no remainder.
+    Quadruple<MulMN<HalfOf<A>, HalfOf<B>>>: Nat + Remainder<Four, Output = Zero>,
+{
+    type Cofactor = MulMN<HalfOf<A>, HalfOf<B>>;
+    type Proof = Equal<MulMN<A, B>, Quadruple<MulMN<HalfOf<A>, HalfOf<B>>>>;
+    type Divides = <Four as Divides<MulMN<A, B>>>::Output;
+}
+// `4x`, as `(x + x) + (x + x)`.
+type Quadruple<X> = SumMN<SumMN<X, X>, SumMN<X, X>>;
+
+proofs! {
+    <Zero as AsDouble>::Proof;
+    <Eight as AsDouble>::Proof;
+    Equal<Three, <Six as AsDouble>::Half>;
+    <Two as FourDividesProduct<Two>>::Proof;
+    <Two as FourDividesProduct<Two>>::Divides;
+    <Six as FourDividesProduct<Four>>::Proof;
+    <Six as FourDividesProduct<Four>>::Divides;
+    Equal<Six, <Six as FourDividesProduct<Four>>::Cofactor>;
+    <Zero as FourDividesProduct<Eight>>::Divides;
+    <Four as FourDividesProduct<Two>>::Divides;
+}
+// Both of them have to be even. `2·3 = 6`, and four doesn't divide that:
+// proof! { <Two as FourDividesProduct<Three>>::Divides }
+// proof! { <Three as AsDouble>::Half }
+
+// ### Geometric series.
+
+type Geo<R, N> = <N as GeometricSum<R>>::Output;
+type Pow<R, N> = <R as Power<N>>::Output;
+// `q = r - 1`.
+type PredOf<R> = <R as Difference<One>>::Output;
+
+/// `(r - 1)(1 + r + ... + rⁿ) = rⁿ⁺¹ - 1`, by induction on `n`.
+///
+/// This is for `r ≥ 1`, so that `q = r - 1` doesn't underflow. At `r = 1`
+/// both sides are `0`, and the identity only says something from `r = 2` on. The right side
+/// never underflows either, since `rⁿ⁺¹ ≥ 1`. The induction carries it as `q·S(n) + 1 = rⁿ⁺¹`:
+/// - `q·1 + 1 = q + 1 = r`, by `ZeroSum` and `OneProduct`.
+/// - `rⁿ⁺² = rⁿ⁺¹(1 + q) = q·rⁿ⁺¹ + (q·S(n) + 1) = q·(S(n) + rⁿ⁺¹) + 1`, by distributivity,
+///   commutativity, induction, rearranging the sum, and distributivity again.
+///
+/// `1 + q = r` is `SumUndoesDifference` and `SumCommutes`. Those, `ZeroSum`, `OneProduct`,
+/// `ProductCommutes`, `ProductDistributes` and `SumOntoSuccessor` are all proved above, so this
+/// holds for every `r ≥ 1` and `n` outright.
+pub trait GeometricSeries<R: Nat>: Nat {
+    type Proof;
+}
+impl<R: Nat> GeometricSeries<R> for Zero where
+    R: Difference<One>,
+    PredOf<R>: Nat,
+    // `q·1 = 0 + q = q`, and `r¹ = 1·r = r`.
+    PredOf<R>: ZeroSum,
+    Zero: Sum<PredOf<R>, Output = PredOf<R>>,
+    One: OneProduct + Product<R, Output = R>,
+{
+    type Proof = Equal<MulMN<PredOf<R>, One>, <Pow<R, One> as Difference<One>>::Output>;
+}
+impl<R: Nat, N: Nat> GeometricSeries<R> for Successor<N> where
+    // Names for `q`, `S(n)` and `p = rⁿ⁺¹`.
+    R: Difference<One> + Power<Successor<N>>,
+    PredOf<R>: Nat,
+    N: GeometricSum<R>,
+    Successor<N>: GeometricSum<R>,
+    Pow<R, Successor<N>>: Nat + Product<PredOf<R>> + Product<R>,
+    PredOf<R>: Product<Geo<R, N>> + Product<Pow<R, Successor<N>>> + Product<Geo<R, Successor<N>>>,
+    MulMN<PredOf<R>, Geo<R, N>>: Nat + Sum<One> + Sum<MulMN<PredOf<R>, Pow<R, Successor<N>>>>,
+    SumMN<MulMN<PredOf<R>, Geo<R, N>>, MulMN<PredOf<R>, Pow<R, Successor<N>>>>: Nat,
+    MulMN<PredOf<R>, Pow<R, Successor<N>>>: Nat + Sum<Pow<R, Successor<N>>>,
+    // Induction hypothesis: `q·S(n) + 1 = p`.
+    N: GeometricSeries<R>,
+    MulMN<PredOf<R>, Geo<R, N>>: Sum<One, Output = Pow<R, Successor<N>>>,
+    // `1 + q = q + 1 = r`, by `SumCommutes` and `SumUndoesDifference`.
+    R: SumUndoesDifference<One>,
+    PredOf<R>: Sum<One, Output = R>,
+    PredOf<R>: SumCommutes<One>,
+    One: Sum<PredOf<R>, Output = R>,
+    // `pr = p(1 + q) = p·1 + pq = p + pq`, by `ProductDistributes` and `ZeroSum`,
+    PredOf<R>: ProductDistributes<Pow<R, Successor<N>>, One>,
+    Pow<R, Successor<N>>: ZeroSum,
+    Zero: Sum<Pow<R, Successor<N>>, Output = Pow<R, Successor<N>>>,
+    Pow<R, Successor<N>>: Product<R, Output = SumMN<Pow<R, Successor<N>>, MulMN<Pow<R, Successor<N>>, PredOf<R>>>>,
+    // `pq = qp`, by `ProductCommutes`,
+    PredOf<R>: ProductCommutes<Pow<R, Successor<N>>>,
+    Pow<R, Successor<N>>: Product<PredOf<R>, Output = MulMN<PredOf<R>, Pow<R, Successor<N>>>>,
+    // and `p + qp = qp + p`, by `SumCommutes`.
+    MulMN<PredOf<R>, Pow<R, Successor<N>>>: SumCommutes<Pow<R, Successor<N>>>,
+    Pow<R, Successor<N>>: Sum<
+        MulMN<PredOf<R>, Pow<R, Successor<N>>>,
+        Output = SumMN<MulMN<PredOf<R>, Pow<R, Successor<N>>>, Pow<R, Successor<N>>>,
+    >,
+    // `qp + (q·S(n) + 1) = (q·S(n) + qp) + 1`, by `SumOntoSuccessor` with `p = q·S(n) + 1`.
+    Pow<R, Successor<N>>: SumOntoSuccessor<MulMN<PredOf<R>, Pow<R, Successor<N>>>, MulMN<PredOf<R>, Geo<R, N>>>,
+    MulMN<PredOf<R>, Pow<R, Successor<N>>>: Sum<
+        SumMN<MulMN<PredOf<R>, Geo<R, N>>, One>,
+        Output = Successor<SumMN<MulMN<PredOf<R>, Geo<R, N>>, MulMN<PredOf<R>, Pow<R, Successor<N>>>>>,
+    >,
+    // `q(S(n) + p) = q·S(n) + qp`, by `ProductDistributes`.
+    Pow<R, Successor<N>>: ProductDistributes<PredOf<R>, Geo<R, N>>,
+    PredOf<R>: Product<
+        Geo<R, Successor<N>>,
+        Output = SumMN<MulMN<PredOf<R>, Geo<R, N>>, MulMN<PredOf<R>, Pow<R, Successor<N>>>>,
+    >,
+{
+    type Proof = Equal<
+        MulMN<PredOf<R>, Geo<R, Successor<N>>>,
+        <Pow<R, Successor<Successor<N>>> as Difference<One>>::Output,
+    >;
+}
+
+proofs! {
+    <Zero as GeometricSeries<Two>>::Proof;
+    <Three as GeometricSeries<Two>>::Proof;
+    <Two as GeometricSeries<Three>>::Proof;
+    <Four as GeometricSeries<One>>::Proof;
+    // `2·(1 + 3 + 9) = 26 = 27 - 1`.
+    Equal<<<Two as GeometricSum<Three>>::Output as Product<Two>>::Output,
+          <<Three as Power<Three>>::Output as Difference<One>>::Output>;
+}
+// There's no `r - 1` for `r = 0`:
+// proof! { <Two as GeometricSeries<Zero>>::Proof }
+
+// ### The doubling series.
+
+// `1 + 2 + 4 + ... + 2ⁿ`, as a list of powers of two added up.
+type DoublingList<N> = <RangeList<Zero, Successor<N>> as MapList<PowerOf<Two>>>::Output;
+
+/// `1 + 2 + 4 + ... + 2ⁿ = 2ⁿ⁺¹ - 1`, by induction on `n`, carried as `S(n) + 1 = 2ⁿ⁺¹`:
+/// - `1 + 1 = 2`.
+/// - `(S(n) + 2ⁿ⁺¹) + 1 = 2ⁿ⁺¹ + (S(n) + 1) = 2ⁿ⁺¹ + 2ⁿ⁺¹ = 2ⁿ⁺²`, by rearranging the sum,
+///   induction, and `p·2 = p·1 + p = p + p`.
+///
+/// This is `GeometricSeries` at `r = 2`, without the `(r - 1)·` in front. The rearrangement is
+/// `SumOntoSuccessor`.
+///
+/// `R` is only ever `Two`, which is all the base case is proved for. It's a parameter so that
+/// rustc doesn't try to unfold `2ⁿ⁺¹` while checking the step for every `n`. That also means the
+/// step can't see `p·2 = p + p` for itself, so the doubling is checked at each concrete `n`.
+pub trait DoublingSeries<R: Nat>: Nat {
+    type Proof;
+}
+impl DoublingSeries<Two> for Zero {
+    type Proof = Equal<SumMN<Geo<Two, Zero>, One>, Pow<Two, One>>;
+}
+impl<R: Nat, N: Nat> DoublingSeries<R> for Successor<N> where
+    // Names for `S(n)` and `p = 2ⁿ⁺¹`.
+    N: GeometricSum<R>,
+    R: Power<Successor<N>>,
+    Geo<R, N>: Nat + Sum<Pow<R, Successor<N>>>,
+    SumMN<Geo<R, N>, Pow<R, Successor<N>>>: Nat,
+    Pow<R, Successor<N>>: Nat,
+    // Induction hypothesis: `S(n) + 1 = p`.
+    N: DoublingSeries<R>,
+    Geo<R, N>: Sum<One, Output = Pow<R, Successor<N>>>,
+    // The induction hypothesis, then `SumOntoSuccessor`: `p + p = p + (S(n) + 1) = (S(n) + p) + 1`.
+    Pow<R, Successor<N>>: SumOntoSuccessor<Pow<R, Successor<N>>, Geo<R, N>>,
+    Pow<R, Successor<N>>: Sum<Pow<R, Successor<N>>, Output = Successor<SumMN<Geo<R, N>, Pow<R, Successor<N>>>>>,
+    // Doubling: `p·2 = p + p`.
+    Pow<R, Successor<N>>: Product<R, Output = SumMN<Pow<R, Successor<N>>, Pow<R, Successor<N>>>>,
+{
+    type Proof = Equal<SumMN<Geo<R, Successor<N>>, One>, Pow<R, Successor<Successor<N>>>>;
+}
+
+proofs! {
+    <Zero as DoublingSeries<Two>>::Proof;
+    <One as DoublingSeries<Two>>::Proof;
+    <Four as DoublingSeries<Two>>::Proof;
+    // The same sum, as a list: `1 + 2 + ... + 2ⁿ = 2ⁿ⁺¹ - 1`.
+    Equal<<DoublingList<Zero> as SumList>::Output, DiffMN<Pow<Two, One>, One>>;
+    Equal<<DoublingList<One> as SumList>::Output, DiffMN<Pow<Two, Two>, One>>;
+    Equal<<DoublingList<Two> as SumList>::Output, DiffMN<Pow<Two, Three>, One>>;
+    Equal<<DoublingList<Three> as SumList>::Output, DiffMN<Pow<Two, Four>, One>>;
+    Equal<<DoublingList<Four> as SumList>::Output, DiffMN<Pow<Two, Five>, One>>;
+    Equal<<DoublingList<Five> as SumList>::Output, DiffMN<Pow<Two, Six>, One>>;
+    Equal<<DoublingList<Five> as SumList>::Output, Geo<Two, Five>>;
+}
+// It's only true for doubling:
+// proof! { <Two as DoublingSeries<Three>>::Proof }
+// And `2⁷ = 128` is past how deep rustc will go:
+// proof! { Equal<<DoublingList<Six> as SumList>::Output, DiffMN<Pow<Two, Seven>, One>> }
+
+// And by value, against the closed form.
+const _: () = assert!(<<DoublingList<Zero> as SumList>::Output as Reify>::OUTPUT == (1 << 1) - 1);
+const _: () = assert!(<<DoublingList<Three> as SumList>::Output as Reify>::OUTPUT == (1 << 4) - 1);
+const _: () = assert!(<<DoublingList<Five> as SumList>::Output as Reify>::OUTPUT == (1 << 6) - 1);
+
+// ### Binary and Peano naturals agree.
+
+type Peano<B> = <B as ToPeano>::Output;
+type Bin<N> = <N as ToBinary>::Output;
+type Inc<B> = <B as Increment>::Output;
+
+/// Adding one in binary is adding one in Peano: `Peano(b + 1) = Peano(b) + 1`.
+///
+/// By induction on the bits of `b`, with `m = Peano(b)` below the bottom bit:
+/// - `0 + 1 = 1`.
+/// - `2m + 1` is what `PushOne` means, so flipping a bottom zero to a one needs nothing more.
+/// - `(2m + 1) + 1 = 2(m + 1)`, which is `(m + 1) + (m + 1) = (m + m) + 2`, by induction and
+///   `SuccessorSum` twice.
+pub trait IncrementAgrees: Increment {
+    type Proof;
+}
+impl IncrementAgrees for BZero {
+    type Proof = Equal<Peano<Inc<BZero>>, Successor<Peano<BZero>>>;
+}
+impl<M: BinNat + ToPeano> IncrementAgrees for PushZero<M> where
+    Peano<M>: Sum<Peano<M>>,
+    SumMN<Peano<M>, Peano<M>>: Nat,
+{
+    type Proof = Equal<Peano<Inc<PushZero<M>>>, Successor<Peano<PushZero<M>>>>;
+}
+impl<M: Increment + ToPeano> IncrementAgrees for PushOne<M> where
+    Peano<M>: Sum<Peano<M>>,
+    SumMN<Peano<M>, Peano<M>>: Nat,
+    // Induction hypothesis: `Peano(b + 1) = m + 1`.
+    M: IncrementAgrees,
+    Inc<M>: ToPeano<Output = Successor<Peano<M>>>,
+    // `(m + 2) + m = (m + m) + 2`.
+    Peano<M>: SuccessorSum<Peano<M>> + SuccessorSum<Successor<Peano<M>>>,
+    Successor<Successor<Peano<M>>>: Sum<Peano<M>, Output = Successor<Successor<SumMN<Peano<M>, Peano<M>>>>>,
+{
+    type Proof = Equal<Peano<Inc<PushOne<M>>>, Successor<Peano<PushOne<M>>>>;
+}
+
+/// Peano to binary and back gives the same natural: `Peano(Bin(n)) = n`.
+///
+/// By induction on `n`: `Bin(n + 1)` is `Bin(n) + 1`, so `Peano(Bin(n + 1)) = Peano(Bin(n)) + 1`
+/// by `IncrementAgrees`, and that's `n + 1` by induction. `IncrementAgrees` is proved above, so
+/// this holds for every `n` outright.
+///
+/// The other way round only holds without leading zeros, since `ToBinary` never makes any:
+///
+/// ```compile_fail
+/// use sqrt_irrational::prelude::*;
+/// type LeadingZero = PushZero<PushOne<PushZero<BZero>>>;
+/// proof! { Equal<LeadingZero, <<LeadingZero as ToPeano>::Output as ToBinary>::Output> }
+/// ```
+pub trait BinaryRoundTrip: ToBinary {
+    type Proof;
+}
+impl BinaryRoundTrip for Zero {
+    type Proof = Equal<Peano<Bin<Zero>>, Zero>;
+}
+impl<N: ToBinary> BinaryRoundTrip for Successor<N> where
+    Bin<N>: Increment,
+    // `Peano(Bin(n) + 1) = Peano(Bin(n)) + 1`.
+    Bin<N>: IncrementAgrees,
+    Inc<Bin<N>>: ToPeano<Output = Successor<Peano<Bin<N>>>>,
+    // Induction hypothesis: `Peano(Bin(n)) = n`.
+    N: BinaryRoundTrip,
+    Bin<N>: ToPeano<Output = N>,
+{
+    type Proof = Equal<Peano<Bin<Successor<N>>>, Successor<N>>;
+}
+
+proofs! {
+    <BZero as IncrementAgrees>::Proof;
+    <PushOne<PushOne<PushOne<BZero>>> as IncrementAgrees>::Proof;
+    <PushZero<PushOne<PushOne<BZero>>> as IncrementAgrees>::Proof;
+    <Zero as BinaryRoundTrip>::Proof;
+    <Seven as BinaryRoundTrip>::Proof;
+    <<Six as Square>::Output as BinaryRoundTrip>::Proof;
+}
+
+// ### Cassini's identity.
+
+type FibCur<N> = <N as FibonacciPair>::Current;
+type FibNext<N> = <N as FibonacciPair>::Next;
+type FibAfter<N> = SumMN<FibCur<N>, FibNext<N>>;
+
+/// `F(n - 1)·F(n + 1) - F(n)² = (-1)ⁿ`, for `n ≥ 1`, with `Self` as `n`.
+pub trait CassiniSigned: Nat {
+    type Output: Int;
+}
+impl<N: Nat> CassiniSigned for Successor<N> where
+    N: Fibonacci,
+    Successor<Successor<N>>: Fibonacci,
+    Successor<N>: Fibonacci,
+    <N as Fibonacci>::Output: Product<<Successor<Successor<N>> as Fibonacci>::Output>,
+    <Successor<N> as Fibonacci>::Output: Product<<Successor<N> as Fibonacci>::Output>,
+    <<Successor<N> as Fibonacci>::Output as Square>::Output: Nat,
+    MulMN<<N as Fibonacci>::Output, <Successor<Successor<N>> as Fibonacci>::Output>:
+        Nat + SignedDifference<<<Successor<N> as Fibonacci>::Output as Square>::Output>,
+{
+    type Output = <MulMN<<N as Fibonacci>::Output, <Successor<Successor<N>> as Fibonacci>::Output> as SignedDifference<
+        <<Successor<N> as Fibonacci>::Output as Square>::Output,
+    >>::Output;
+}
+
+proofs! {
+    Equal<Neg<One>, <One as CassiniSigned>::Output>;
+    Equal<Pos<One>, <Two as CassiniSigned>::Output>;
+    Equal<Neg<One>, <Three as CassiniSigned>::Output>;
+    Equal<Pos<One>, <Four as CassiniSigned>::Output>;
+    Equal<Neg<One>, <Five as CassiniSigned>::Output>;
+}
+const _: () = assert!(<<Two as CassiniSigned>::Output as ReifyInt>::OUTPUT == 1);
+const _: () = assert!(<<Three as CassiniSigned>::Output as ReifyInt>::OUTPUT == -1);
+const _: () = assert!(<<Four as CassiniSigned>::Output as ReifyInt>::OUTPUT == 1);
+const _: () = assert!(<<Five as CassiniSigned>::Output as ReifyInt>::OUTPUT == -1);
+
+/// Cassini's identity for every `n`, by induction, written without subtraction:
+/// `F(n)·F(n + 2) + e = F(n + 1)² + o`, where `e` is `1` when `n` is even and `0` otherwise,
+/// and `o` is the other way around. Shifting `n` by one, that's `CassiniSigned`.
+///
+/// Write `a = F(n)`, `b = F(n + 1)` and `c = F(n + 2) = a + b`. At `n = 0`, it's `0 + 1 = 1 + 0`.
+/// The step has to show `b(b + c) + o = c² + e`, and
+/// - `b(b + c) + o = (b² + o) + bc`, by distributivity and rearranging,
+/// - `b² + o = ac + e`, by induction,
+/// - `c² = (a + b)c = ac + bc`, by distributivity, and rearranging gives `(ac + e) + bc`.
+///
+/// The expansions are `ProductDistributes` and `ProductMonotone`, and both rearrangements are
+/// `SumSwap`. They're all proved above, so this holds for every `n` outright.
+pub trait CassiniIdentity: Nat {
+    // `e` and `o`.
+    type EvenTerm: Nat;
+    type OddTerm: Nat;
+    type Proof;
+}
+impl CassiniIdentity for Zero {
+    type EvenTerm = One;
+    type OddTerm = Zero;
+    type Proof = Equal<SumMN<MulMN<FibCur<Zero>, FibAfter<Zero>>, One>, SumMN<MulMN<FibNext<Zero>, FibNext<Zero>>, Zero>>;
+}
+impl<N: Nat> CassiniIdentity for Successor<N> where
+    // Names for `a`, `b`, `c` and the products of them.
+    N: FibonacciPair,
+    FibCur<N>: Sum<FibNext<N>> + Product<FibAfter<N>>,
+    FibNext<N>: Sum<FibAfter<N>> + Product<FibNext<N>> + Product<FibAfter<N>>,
+    FibAfter<N>: Nat + Product<FibAfter<N>>,
+    SumMN<FibNext<N>, FibAfter<N>>: Nat,
+    MulMN<FibCur<N>, FibAfter<N>>: Nat + Sum<<N as CassiniIdentity>::EvenTerm> + Sum<MulMN<FibNext<N>, FibAfter<N>>>,
+    MulMN<FibNext<N>, FibNext<N>>: Nat + Sum<MulMN<FibNext<N>, FibAfter<N>>>,
+    MulMN<FibNext<N>, FibAfter<N>>: Nat + Sum<<N as CassiniIdentity>::OddTerm> + Sum<<N as CassiniIdentity>::EvenTerm>,
+    <N as CassiniIdentity>::OddTerm: Sum<MulMN<FibNext<N>, FibAfter<N>>>,
+    <N as CassiniIdentity>::EvenTerm: Sum<MulMN<FibNext<N>, FibAfter<N>>>,
+    SumMN<MulMN<FibCur<N>, FibAfter<N>>, <N as CassiniIdentity>::EvenTerm>: Nat + Sum<MulMN<FibNext<N>, FibAfter<N>>>,
+    SumMN<MulMN<FibNext<N>, FibNext<N>>, MulMN<FibNext<N>, FibAfter<N>>>: Sum<<N as CassiniIdentity>::OddTerm>,
+    SumMN<MulMN<FibCur<N>, FibAfter<N>>, MulMN<FibNext<N>, FibAfter<N>>>: Sum<<N as CassiniIdentity>::EvenTerm>,
+    // Induction hypothesis: `b² + o = ac + e`.
+    N: CassiniIdentity,
+    MulMN<FibNext<N>, FibNext<N>>: Sum<
+        <N as CassiniIdentity>::OddTerm,
+        Output = SumMN<MulMN<FibCur<N>, FibAfter<N>>, <N as CassiniIdentity>::EvenTerm>,
+    >,
+    // `b(b + c) = b² + bc`, by `ProductDistributes`.
+    FibAfter<N>: ProductDistributes<FibNext<N>, FibNext<N>>,
+    FibNext<N>: Product<
+        SumMN<FibNext<N>, FibAfter<N>>,
+        Output = SumMN<MulMN<FibNext<N>, FibNext<N>>, MulMN<FibNext<N>, FibAfter<N>>>,
+    >,
+    // `(b² + bc) + o = (b² + o) + bc`, by `SumSwap`.
+    MulMN<FibNext<N>, FibNext<N>>: SumSwap<MulMN<FibNext<N>, FibAfter<N>>, <N as CassiniIdentity>::OddTerm>,
+    SumMN<MulMN<FibNext<N>, FibNext<N>>, MulMN<FibNext<N>, FibAfter<N>>>: Sum<
+        <N as CassiniIdentity>::OddTerm,
+        Output = SumMN<SumMN<MulMN<FibNext<N>, FibNext<N>>, <N as CassiniIdentity>::OddTerm>, MulMN<FibNext<N>, FibAfter<N>>>,
+    >,
+    SumMN<MulMN<FibNext<N>, FibNext<N>>, <N as CassiniIdentity>::OddTerm>: Sum<MulMN<FibNext<N>, FibAfter<N>>>,
+    // `c² = (a + b)c = ac + bc`, by `ProductMonotone`.
+    FibAfter<N>: ProductMonotone<FibCur<N>, FibNext<N>>,
+    FibAfter<N>: Product<
+        FibAfter<N>,
+        Output = SumMN<MulMN<FibCur<N>, FibAfter<N>>, MulMN<FibNext<N>, FibAfter<N>>>,
+    >,
+    // `(ac + bc) + e = (ac + e) + bc`, by `SumSwap`.
+    MulMN<FibCur<N>, FibAfter<N>>: SumSwap<MulMN<FibNext<N>, FibAfter<N>>, <N as CassiniIdentity>::EvenTerm>,
+    SumMN<MulMN<FibCur<N>, FibAfter<N>>, MulMN<FibNext<N>, FibAfter<N>>>: Sum<
+        <N as CassiniIdentity>::EvenTerm,
+        Output = SumMN<SumMN<MulMN<FibCur<N>, FibAfter<N>>, <N as CassiniIdentity>::EvenTerm>, MulMN<FibNext<N>, FibAfter<N>>>,
+    >,
+{
+    type EvenTerm = <N as CassiniIdentity>::OddTerm;
+    type OddTerm = <N as CassiniIdentity>::EvenTerm;
+    type Proof = Equal<
+        SumMN<MulMN<FibNext<N>, SumMN<FibNext<N>, FibAfter<N>>>, <N as CassiniIdentity>::OddTerm>,
+        SumMN<MulMN<FibAfter<N>, FibAfter<N>>, <N as CassiniIdentity>::EvenTerm>,
+    >;
+}
+
+proofs! {
+    <Zero as CassiniIdentity>::Proof;
+    <One as CassiniIdentity>::Proof;
+    <Four as CassiniIdentity>::Proof;
+    <Five as CassiniIdentity>::Proof;
+    Equal<One, <Four as CassiniIdentity>::EvenTerm>;
+    Equal<One, <Five as CassiniIdentity>::OddTerm>;
+}
+
+// ### Pythagorean triples.
+
+proofs! {
+    // `3² + 4² = 5²`.
+    Equal<<Five as Square>::Output, <<Three as Square>::Output as Sum<<Four as Square>::Output>>::Output>;
+}
+
+type Sq<X> = <X as Square>::Output;
+type TripleA<M, N> = <Sq<M> as Difference<Sq<N>>>::Output;
+type TripleB<M, N> = Twice<MulMN<M, N>>;
+type TripleC<M, N> = SumMN<Sq<M>, Sq<N>>;
+type Twice<X> = SumMN<X, X>;
+// `p = as` and `q = s²`, the two kinds of term that `c² - a²` and `b²` break into,
+type TripleP<A, S> = MulMN<A, S>;
+type TripleQ<S> = MulMN<S, S>;
+// and `4p + 4q`, which both of them come out to.
+type TripleX<A, S> = SumMN<Twice<Twice<TripleP<A, S>>>, Twice<Twice<TripleQ<S>>>>;
+
+/// Euclid's formula: for `m > n`, `(m² - n²)² + (2mn)² = (m² + n²)²`.
+///
+/// Write `a = m² - n²` and `k = mn`. `a + n² = m²`, since `n² < m²`, so `m² + n² = a + 2n²` by
+/// `SumAssociative`, and `k² = m²n²` by `ProductExchange`. `EuclidTripleBy` does the rest.
+///
+/// Everything but `m > n` giving `m² ≥ n²`, which `SumUndoesDifference` needs, is proved for
+/// all `m` and `n`. That one step is left to each concrete pair.
+pub trait EuclidTriple<N> {
+    type Legs;
+    type Hypotenuse;
+    type Proof;
+}
+impl<M: Nat, N: Nat> EuclidTriple<N> for M where
+    // Hypothesis: `m > n`.
+    N: LessThan<M>,
+    // Names for `m²`, `n²`, `a`, `k` and their sums.
+    M: Square + Product<N>,
+    N: Square + Product<M>,
+    Sq<M>: Nat + Difference<Sq<N>> + Sum<Sq<N>> + Product<Sq<N>>,
+    Sq<N>: Nat + Sum<Sq<N>>,
+    Twice<Sq<N>>: Nat,
+    TripleA<M, N>: Nat + Sum<Sq<N>> + Sum<Twice<Sq<N>>>,
+    MulMN<M, N>: Nat + Sum<MulMN<M, N>> + Product<MulMN<M, N>>,
+    TripleB<M, N>: Nat,
+    // `a + n² = m²`, because `m > n`, by `SumUndoesDifference`,
+    Sq<M>: SumUndoesDifference<Sq<N>>,
+    TripleA<M, N>: Sum<Sq<N>, Output = Sq<M>>,
+    // so `a + 2n² = (a + n²) + n² = m² + n²`, by `SumAssociative`.
+    Sq<N>: SumAssociative<TripleA<M, N>, Sq<N>>,
+    TripleA<M, N>: Sum<Twice<Sq<N>>, Output = TripleC<M, N>>,
+    // `k² = (mn)(mn) = (mm)(nn) = m²n²`, by `ProductExchange`.
+    N: ProductExchange<M, N, M>,
+    MulMN<M, N>: Product<MulMN<M, N>, Output = MulMN<Sq<M>, Sq<N>>>,
+    // The rest is `EuclidTripleBy`.
+    TripleA<M, N>: EuclidTripleBy<Sq<N>, Twice<Sq<N>>, MulMN<M, N>, TripleB<M, N>>,
+{
+    type Legs = (TripleA<M, N>, TripleB<M, N>);
+    type Hypotenuse = TripleC<M, N>;
+    type Proof = <TripleA<M, N> as EuclidTripleBy<Sq<N>, Twice<Sq<N>>, MulMN<M, N>, TripleB<M, N>>>::Proof;
+}
+/// `EuclidTriple`, with `a`, `s = n²`, `d = s + s`, `k = mn` and `b = k + k` given their own
+/// names: if `k² = (a + s)s`, then `(a + d)² = a² + b²`.
+///
+/// Write `c = a + d`, `p = as` and `q = s²`. Then:
+/// - `c² = c(a + d) = (a² + da) + (ad + d²)`, and multiplying out `d = s + s` makes that
+///   `(a² + 2p) + (2p + 4q)`, which is `a² + (4p + 4q)`.
+/// - `b² = (k + k)² = 4k²`, and `k² = (a + s)s = p + q`. Sorting the four copies of `p + q`
+///   gives `4p + 4q`.
+///
+/// The products are multiplied out with `ProductDistributes`, `ProductMonotone` and
+/// `ProductCommutes`, and the sums sorted with `SumAssociative` and `SumExchange`. They're all
+/// proved above, so this holds for every `a`, `s` and `k` outright.
+pub trait EuclidTripleBy<S: Nat, D: Nat, K: Nat, B: Nat>: Nat {
+    type Proof;
+}
+impl<A: Nat, S: Nat, D: Nat, K: Nat, B: Nat> EuclidTripleBy<S, D, K, B> for A where
+    // Names for everything, as the aliases above compute them.
+    A: Sum<S> + Sum<D> + Product<A> + Product<S> + Product<D>,
+    S: Sum<S> + Product<A> + Product<S>,
+    SumMN<A, S>: Nat + Product<S>,
+    SumMN<A, D>: Nat + Product<A> + Product<D>,
+    MulMN<SumMN<A, D>, A>: Nat + Sum<MulMN<SumMN<A, D>, D>>,
+    MulMN<SumMN<A, D>, D>: Nat,
+    D: Nat + Product<A> + Product<D> + Product<S>,
+    MulMN<A, A>: Nat + Sum<MulMN<D, A>>,
+    MulMN<D, A>: Nat,
+    MulMN<A, D>: Nat + Sum<MulMN<D, D>>,
+    MulMN<D, D>: Nat,
+    MulMN<S, A>: Nat + Sum<MulMN<S, A>>,
+    MulMN<D, S>: Nat + Sum<MulMN<D, S>>,
+    K: Sum<K> + Product<K> + Product<B>,
+    MulMN<K, K>: Nat + Sum<MulMN<K, K>>,
+    B: Nat + Product<K> + Product<B>,
+    MulMN<B, K>: Nat + Sum<MulMN<B, K>>,
+    MulMN<A, A>: Sum<Twice<TripleP<A, S>>> + Sum<TripleX<A, S>> + Sum<MulMN<B, B>>,
+    MulMN<B, B>: Nat,
+    TripleP<A, S>: Nat + Sum<TripleP<A, S>> + Sum<TripleQ<S>>,
+    TripleQ<S>: Nat + Sum<TripleQ<S>> + Sum<TripleP<A, S>>,
+    Twice<TripleP<A, S>>: Nat + Sum<Twice<TripleP<A, S>>> + Sum<Twice<TripleQ<S>>> + Sum<Twice<Twice<TripleQ<S>>>>
+        + Sum<SumMN<Twice<TripleP<A, S>>, Twice<Twice<TripleQ<S>>>>>,
+    Twice<TripleQ<S>>: Nat + Sum<Twice<TripleQ<S>>> + Sum<Twice<TripleP<A, S>>>,
+    Twice<Twice<TripleP<A, S>>>: Nat + Sum<Twice<Twice<TripleQ<S>>>>,
+    Twice<Twice<TripleQ<S>>>: Nat,
+    SumMN<TripleP<A, S>, TripleQ<S>>: Nat + Sum<SumMN<TripleP<A, S>, TripleQ<S>>>,
+    SumMN<Twice<TripleP<A, S>>, Twice<TripleQ<S>>>: Nat + Sum<SumMN<Twice<TripleP<A, S>>, Twice<TripleQ<S>>>>,
+    SumMN<Twice<TripleP<A, S>>, Twice<Twice<TripleQ<S>>>>: Nat,
+    SumMN<MulMN<A, A>, Twice<TripleP<A, S>>>: Nat + Sum<SumMN<Twice<TripleP<A, S>>, Twice<Twice<TripleQ<S>>>>>,
+    TripleX<A, S>: Nat,
+    // Hypotheses: `d = s + s`, `b = k + k` and `k² = (a + s)s`.
+    S: Sum<S, Output = D>,
+    K: Sum<K, Output = B>,
+    K: Product<K, Output = MulMN<SumMN<A, S>, S>>,
+    // `c² = c(a + d) = ca + cd`, by `ProductDistributes`.
+    D: ProductDistributes<SumMN<A, D>, A>,
+    SumMN<A, D>: Product<
+        SumMN<A, D>,
+        Output = SumMN<MulMN<SumMN<A, D>, A>, MulMN<SumMN<A, D>, D>>,
+    >,
+    // `ca = (a + d)a = a² + da`, by `ProductMonotone`,
+    A: ProductMonotone<A, D>,
+    SumMN<A, D>: Product<A, Output = SumMN<MulMN<A, A>, MulMN<D, A>>>,
+    // and `da = (s + s)a = sa + sa = 2p`, by `ProductMonotone` and `ProductCommutes`.
+    A: ProductMonotone<S, S>,
+    D: Product<A, Output = Twice<MulMN<S, A>>>,
+    A: ProductCommutes<S>,
+    S: Product<A, Output = TripleP<A, S>>,
+    // `cd = (a + d)d = ad + d²`, by `ProductMonotone`,
+    D: ProductMonotone<A, D>,
+    SumMN<A, D>: Product<D, Output = SumMN<MulMN<A, D>, MulMN<D, D>>>,
+    // `ad = a(s + s) = 2p`, by `ProductDistributes`,
+    S: ProductDistributes<A, S>,
+    A: Product<D, Output = Twice<TripleP<A, S>>>,
+    // and `d² = d(s + s) = ds + ds`, and `ds = (s + s)s = 2q`, by `ProductDistributes` and
+    // `ProductMonotone`.
+    S: ProductDistributes<D, S>,
+    D: Product<D, Output = Twice<MulMN<D, S>>>,
+    S: ProductMonotone<S, S>,
+    D: Product<S, Output = Twice<TripleQ<S>>>,
+    // `(a² + 2p) + (2p + 4q) = a² + (2p + (2p + 4q)) = a² + (4p + 4q)`, by `SumAssociative` twice.
+    SumMN<Twice<TripleP<A, S>>, Twice<Twice<TripleQ<S>>>>: SumAssociative<MulMN<A, A>, Twice<TripleP<A, S>>>,
+    SumMN<MulMN<A, A>, Twice<TripleP<A, S>>>: Sum<
+        SumMN<Twice<TripleP<A, S>>, Twice<Twice<TripleQ<S>>>>,
+        Output = SumMN<MulMN<A, A>, SumMN<Twice<TripleP<A, S>>, SumMN<Twice<TripleP<A, S>>, Twice<Twice<TripleQ<S>>>>>>,
+    >,
+    Twice<Twice<TripleQ<S>>>: SumAssociative<Twice<TripleP<A, S>>, Twice<TripleP<A, S>>>,
+    Twice<TripleP<A, S>>: Sum<SumMN<Twice<TripleP<A, S>>, Twice<Twice<TripleQ<S>>>>, Output = TripleX<A, S>>,
+    // `(k + k)² = (k + k)k + (k + k)k`, and `(k + k)k = k² + k²`, by `ProductDistributes` and
+    // `ProductMonotone`.
+    K: ProductDistributes<B, K>,
+    B: Product<B, Output = Twice<MulMN<B, K>>>,
+    K: ProductMonotone<K, K>,
+    B: Product<K, Output = Twice<MulMN<K, K>>>,
+    // `k² = (a + s)s = as + s² = p + q`, by `ProductMonotone`,
+    S: ProductMonotone<A, S>,
+    SumMN<A, S>: Product<S, Output = SumMN<TripleP<A, S>, TripleQ<S>>>,
+    // and `4(p + q) = (2p + 2q) + (2p + 2q) = 4p + 4q`, by `SumExchange` twice.
+    TripleP<A, S>: SumExchange<TripleQ<S>, TripleP<A, S>, TripleQ<S>>,
+    SumMN<TripleP<A, S>, TripleQ<S>>: Sum<
+        SumMN<TripleP<A, S>, TripleQ<S>>,
+        Output = SumMN<Twice<TripleP<A, S>>, Twice<TripleQ<S>>>,
+    >,
+    Twice<TripleP<A, S>>: SumExchange<Twice<TripleQ<S>>, Twice<TripleP<A, S>>, Twice<TripleQ<S>>>,
+    SumMN<Twice<TripleP<A, S>>, Twice<TripleQ<S>>>: Sum<
+        SumMN<Twice<TripleP<A, S>>, Twice<TripleQ<S>>>,
+        Output = TripleX<A, S>,
+    >,
+{
+    type Proof = Equal<MulMN<SumMN<A, D>, SumMN<A, D>>, SumMN<MulMN<A, A>, MulMN<B, B>>>;
+}
+
+proofs! {
+    <Two as EuclidTriple<One>>::Proof;
+    Equal<(Three, Four), <Two as EuclidTriple<One>>::Legs>;
+    Equal<Five, <Two as EuclidTriple<One>>::Hypotenuse>;
+    <Three as EuclidTriple<One>>::Proof;
+    Equal<(Eight, Six), <Three as EuclidTriple<One>>::Legs>;
+}
+// `m² - n²` isn't a natural when `m < n`:
+// proof! { <One as EuclidTriple<Two>>::Proof }
+
+// ## Proof
+
+
+// ### By infinite descent.
+//
+// Suppose `a/b` is a square root of two, so `a² = 2b²` with `b > 0`.
+// Then `a²` is even, so `a` is, and `a = 2c`. That makes `4c² = 2b²`, or `b² = 2c²`,
+// so `b/c` is another square root of two. And `b < a`, because `a² = 2b² > b²`.
+// Doing that again and again gives an endless strictly decreasing chain of naturals,
+// and there's no such thing.
+
+/// `a/b` with `a² = 2b²` and `b > 0`.
+pub trait SqrtTwoSolution {}
+impl<A: Nat, B: Nat> SqrtTwoSolution for Ratio<A, B> where
+    A: Square,
+    B: Square + GreaterThan<Zero>,
+    Two: Product<<B as Square>::Output, Output = <A as Square>::Output>,
+{}
+
+/// One step of the descent: a smaller square root of two, built from a bigger one.
+///
+/// That `a` is even comes from the hypothesis, through `EvenSquareRoot`, and `AsDouble` gives
+/// `c` with `a = 2c`. Cancelling a two from `4c² = 2b²`, and `b < a`, are checked at each
+/// concrete `a` and `b`, of which there are none: nothing satisfies the hypothesis.
+pub trait Descent {
+    type SmallerNum: Nat;
+    type SmallerDen: Nat;
+    /// Proof that `SmallerNum` is less than the numerator we started from.
+    type Witness;
+}
+impl<A: Nat, B: Nat> Descent for Ratio<A, B> where
+    // `a² = 2b²` with `b > 0`. The clause on `SqrtTwoSolution`'s impl doesn't carry over,
+    // so the equation is stated again, to rewrite with.
+    Ratio<A, B>: SqrtTwoSolution,
+    A: Square,
+    B: Square,
+    Two: Product<Sq<B>, Output = Sq<A>>,
+    // `a` is even, because `a²` is, by `EvenSquareRoot`.
+    A: EvenSquareRoot<Sq<B>, Proof = EvenWitness<A>>,
+    // `a = 2c`, by `AsDouble`.
+    A: AsDouble,
+    // Cancelling a two from `(2c)² = 4c² = 2b²` gives `b² = 2c²`, with `c > 0` since `a > 0`.
+    HalfOf<A>: Square + GreaterThan<Zero>,
+    Two: Product<Sq<HalfOf<A>>, Output = Sq<B>>,
+    // `b < a`, since `a² = 2b² > b²`.
+    B: LessThan<A>,
+{
+    type SmallerNum = B;
+    type SmallerDen = HalfOf<A>;
+    type Witness = <B as LessThan<A>>::Output;
+}
+
+/// Descending forever, each step strictly shrinking the numerator.
+///
+/// Every `SqrtTwoSolution` is a `Descent`, and a `Descent` to something that descends forever
+/// descends forever too. But a natural is a finite tower of `Successor`s, so there is no
+/// numerator rustc could ever finish checking this for: well-ordering is why there's no impl
+/// anyone can name. Near misses don't even take the first step:
+///
+/// ```compile_fail,E0277
+/// use sqrt_irrational::prelude::*;
+/// use sqrt_irrational::InfiniteDescent;
+/// fn descends_forever<R: InfiniteDescent>() {}
+/// // `3² = 9`, one more than `2·2² = 8`.
+/// descends_forever::<Ratio<Three, Two>>();
+/// ```
+pub trait InfiniteDescent {}
+impl<R: Descent> InfiniteDescent for R where
+    Ratio<<R as Descent>::SmallerNum, <R as Descent>::SmallerDen>: InfiniteDescent,
+{}
+
+// The hypothesis fails for the nearest misses, so there's nothing to descend from:
+// proof! { <Ratio<Three, Two> as Descent>::Witness }
+// proof! { <Ratio<Seven, Five> as Descent>::Witness }
+// proof! { <Ratio<Zero, Zero> as Descent>::Witness }
+proofs! {
+    // `3² = 9` is one more than `2·2² = 8`, which is as close as small numbers get.
+    Equal<<Two as Product<Sq<Two>>>::Output, Eight>;
+    Equal<Sq<Three>, Nine>;
+    // The lemmas the step leans on, where `a² = 2h` does hold: `4² = 2·8`, and `4 = 2·2`.
+    Equal<EvenWitness<Four>, <Four as EvenSquareRoot<Eight>>::Proof>;
+    Equal<Two, HalfOf<Four>>;
+}
+
+// ### Directly.
+//
+// Any fraction can be written in lowest terms, by dividing through by the gcd (see `GcdDivides`).
+// So suppose `a/b` is in lowest terms and `a² = 2b²`, with `b > 0`.
+// As in the descent, `a` is even, `a = 2c`, and `b² = 2c²`, so `b` is even as well.
+// But then two divides both `a` and `b`, so it divides `gcd(a, b) = 1`. It doesn't.
+
+// Each step of the argument gets its own trait, so that if one of them stops going through,
+// rustc's error names the step that broke rather than just a bound on `NoSqrtTwoSolution`.
+// No ratio gets past step 1, so the examples for the later steps are generic functions that
+// take the steps before as hypotheses, and leave out one of the step's own bounds. rustdoc can
+// only check that they fail with E0277, not which bound the error names.
+
+/// Step 1: `a/b` is in lowest terms, and `a² = 2b²` with `b > 0`.
+///
+/// Nothing gets past this step, and the error names it:
+///
+/// ```compile_fail,E0277
+/// use sqrt_irrational::prelude::*;
+/// use sqrt_irrational::LowestTermsSqrtTwo;
+/// fn lowest_terms<R: LowestTermsSqrtTwo>() {}
+/// // In lowest terms, but `3² = 9` isn't `2·2² = 8`.
+/// lowest_terms::<Ratio<Three, Two>>();
+/// ```
+pub trait LowestTermsSqrtTwo {}
+impl<A: Nat, B: Nat> LowestTermsSqrtTwo for Ratio<A, B> where
+    Ratio<A, B>: SqrtTwoSolution,
+    A: Gcd<B, Output = One>,
+{}
+
+/// Step 2: `a` is even, since `a² = 2b²` is, by `EvenSquareRoot`.
+///
+/// ```compile_fail,E0277
+/// use sqrt_irrational::prelude::*;
+/// use sqrt_irrational::{LowestTermsSqrtTwo, NumeratorEven};
+/// fn numerator_even<R: NumeratorEven>() {}
+/// fn step_two<A: Nat, B: Nat>() where
+///     // Step 1, as a hypothesis, with `a²` and `b²` named.
+///     Ratio<A, B>: LowestTermsSqrtTwo,
+///     A: Square,
+///     B: Square,
+/// {
+///     // Step 2 restates `a² = 2b²` to rewrite with, and step 1's impl doesn't carry it here.
+///     numerator_even::<Ratio<A, B>>();
+/// }
+/// ```
+pub trait NumeratorEven {
+    type Witness;
+}
+impl<A: Nat, B: Nat> NumeratorEven for Ratio<A, B> where
+    Ratio<A, B>: LowestTermsSqrtTwo,
+    // Step 1's `a² = 2b²`, stated again to rewrite with.
+    A: Square,
+    B: Square,
+    Two: Product<Sq<B>, Output = Sq<A>>,
+    // `EvenSquareRoot`'s odd branch can't be written, so `a` is even.
+    A: Even + EvenSquareRoot<Sq<B>, Proof = EvenWitness<A>>,
+{
+    type Witness = <A as EvenSquareRoot<Sq<B>>>::Proof;
+}
+
+/// Step 3: writing `a = 2c`, `b² = 2c²`, so `b` is even too, by `EvenSquareRoot` again.
+///
+/// ```compile_fail,E0277
+/// use sqrt_irrational::prelude::*;
+/// use sqrt_irrational::{AsDouble, DenominatorEven, NumeratorEven};
+/// fn denominator_even<R: DenominatorEven>() {}
+/// fn step_three<A: AsDouble, B: Nat>() where
+///     // Step 2, as a hypothesis, and `a = 2c` with `c²` and `b²` named.
+///     Ratio<A, B>: NumeratorEven,
+///     A::Half: Square,
+///     B: Square,
+/// {
+///     // Cancelling the two from `4c² = 2b²` isn't among the hypotheses.
+///     denominator_even::<Ratio<A, B>>();
+/// }
+/// ```
+pub trait DenominatorEven {
+    type Witness;
+}
+impl<A: Nat, B: Nat> DenominatorEven for Ratio<A, B> where
+    Ratio<A, B>: NumeratorEven,
+    // `a = 2c`, by `AsDouble`.
+    A: AsDouble,
+    // Cancelling a two from `(2c)² = 4c² = 2b²`.
+    HalfOf<A>: Square,
+    B: Square,
+    Two: Product<Sq<HalfOf<A>>, Output = Sq<B>>,
+    B: Even + EvenSquareRoot<Sq<HalfOf<A>>, Proof = EvenWitness<B>>,
+{
+    type Witness = <B as EvenSquareRoot<Sq<HalfOf<A>>>>::Proof;
+}
+
+/// Step 4: two divides both `a` and `b`, so it divides `gcd(a, b)`, which is `1`.
+///
+/// ```compile_fail,E0277
+/// use sqrt_irrational::prelude::*;
+/// use sqrt_irrational::{AsDouble, BothEvenContradiction, DenominatorEven, TwoDividesDouble};
+/// fn both_even<R: BothEvenContradiction>() {}
+/// fn step_four<A: AsDouble, B: AsDouble>() where
+///     // Steps 1 to 3, as hypotheses, and two dividing both `a` and `b`.
+///     Ratio<A, B>: DenominatorEven,
+///     A: Gcd<B, Output = One>,
+///     Two: Product<A::Half, Output = A> + Product<B::Half, Output = B>,
+///     A::Half: TwoDividesDouble,
+///     B::Half: TwoDividesDouble,
+///     Two: Divides<A> + Divides<B>,
+///     A: ExtGcd<B>,
+/// {
+///     // `ExtGcd`'s `x` and `y` aren't known for a generic `a` and `b`, so the Bézout step in
+///     // `CommonDivisorDividesGcd` can't be checked.
+///     both_even::<Ratio<A, B>>();
+/// }
+/// ```
+pub trait BothEvenContradiction {
+    type Contradiction;
+}
+impl<A: Nat, B: Nat> BothEvenContradiction for Ratio<A, B> where
+    Ratio<A, B>: DenominatorEven,
+    // Step 1 again, for rewriting with. An impl's `where` clause doesn't carry over
+    // to places that only mention the trait.
+    A: Gcd<B, Output = One>,
+    // Steps 2 and 3 found `a` and `b` even, so `a = 2c` and `b = 2d`, by `AsDouble`,
+    A: AsDouble,
+    B: AsDouble,
+    Two: Product<HalfOf<A>, Output = A> + Product<HalfOf<B>, Output = B>,
+    // and two divides both, by `TwoDividesDouble`.
+    HalfOf<A>: TwoDividesDouble,
+    HalfOf<B>: TwoDividesDouble,
+    Two: Divides<A> + Divides<B>,
+    // A common divisor of `a` and `b` divides their gcd, by `CommonDivisorDividesGcd`, with
+    // `ExtGcd`'s `x` and `y`.
+    A: ExtGcd<B>,
+    Two: CommonDivisorDividesGcd<A, B, <A as ExtGcd<B>>::X, <A as ExtGcd<B>>::Y>,
+    Two: Divides<<A as Gcd<B>>::Output>,
+{
+    // rustc only gets here by rewriting `gcd(a, b)` to `1` in the clause above.
+    type Contradiction = <Two as Divides<One>>::Output;
+}
+
+/// There is no `a/b` in lowest terms whose square is two.
+///
+/// The impl below is for every `Ratio<A, B>` at once, and asks for `BothEvenContradiction`,
+/// whose `where` clauses carry the four steps above. Some of what they need is proved for every
+/// natural: `a` and `b` are even because `a² = 2b²`, through `EvenSquareRoot`, up to the fact
+/// that nothing is both even and odd, and `AsDouble` and `TwoDividesDouble` turn that into two
+/// dividing each of them. The rest is only checked at concrete numbers: cancelling the two from
+/// `4c² = 2b²`, and the Bézout step in `CommonDivisorDividesGcd`. With those, the only way step
+/// 4 accepts `Contradiction` is "two divides one", so there is no pair of naturals that gets
+/// that far.
+pub trait NoSqrtTwoSolution {
+    type Contradiction;
+}
+impl<A: Nat, B: Nat> NoSqrtTwoSolution for Ratio<A, B> where
+    Ratio<A, B>: BothEvenContradiction,
+{
+    type Contradiction = <Ratio<A, B> as BothEvenContradiction>::Contradiction;
+}
+
+// "Two divides one" really is false, so `Contradiction` can't be named:
+// proof! { <Two as Divides<One>>::Output }
+// proof! { <Ratio<Three, Two> as NoSqrtTwoSolution>::Contradiction }
+proofs! {
+    // Without lowest terms there is no contradiction. Two divides `4`, `6`, and `gcd(4, 6)`:
+    <Two as Divides<Four>>::Output;
+    <Two as Divides<Six>>::Output;
+    <Two as Divides<<Four as Gcd<Six>>::Output>>::Output;
+}
@@ -0,0 +1,731 @@
+//! Rationals, as unreduced pairs of naturals.
+
+use ::core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use ::alloc::{vec, vec::Vec};
+#[cfg(feature = "json")]
+use ::alloc::string::String;
+
+use crate::int::{Int, IntAbs, Neg, Pos, ReifyInt, SignedDifference};
+use crate::list::{TCons, TNil};
+use crate::nat::*;
+use crate::proof::{Congruent, Equal, ProductCommutes, ProductExchange, ProductMonotone, QuotientOne};
+
+// Oh boy, let's try and do rationals now.
+pub struct Ratio<Numerator: Nat, Denominator: Nat> { _a: PhantomData<Numerator>, _b: PhantomData<Denominator> }
+
+/// A ratio with a nonzero denominator, which is the only kind the arithmetic traits accept.
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// proof! { <Ratio<One, Two> as RatioAdd<Ratio<One, Three>>>::Output }
+/// ```
+///
+/// ```compile_fail
+/// use sqrt_irrational::prelude::*;
+/// proof! { <Ratio<One, Zero> as RatioAdd<Ratio<One, Three>>>::Output }
+/// ```
+pub trait ValidRatio {}
+impl<N: Nat, D: Nat> ValidRatio for Ratio<N, D> where D: GreaterThan<Zero> {}
+
+pub trait ReifyRatio {
+    const OUTPUT: (u64, u64);
+    /// The nearest `f64`, give or take a rounding error from the division.
+    const AS_F64: f64 = Self::OUTPUT.0 as f64 / Self::OUTPUT.1 as f64;
+    fn val(&self) -> (u64, u64) { Self::OUTPUT }
+    fn as_f64() -> f64 { Self::AS_F64 }
+    /// The ratio as a JSON object, unreduced, like `{"num":2,"den":4}`.
+    ///
+    /// ```
+    /// use sqrt_irrational::prelude::*;
+    /// type R = <Ratio<One, Two> as RatioAdd<Ratio<One, Three>>>::Output;
+    /// assert_eq!(R::OUTPUT, (5, 6));
+    /// assert_eq!(R::to_json(), r#"{"num":5,"den":6}"#);
+    /// ```
+    #[cfg(feature = "json")]
+    fn to_json() -> String { ::alloc::format!("{{\"num\":{},\"den\":{}}}", Self::OUTPUT.0, Self::OUTPUT.1) }
+}
+impl<N: Nat + Reify, D: Nat + Reify> ReifyRatio for Ratio<N, D> {
+    const OUTPUT: (u64, u64) = (N::OUTPUT, D::OUTPUT);
+}
+/// A list of ratios as `(numerator, denominator)` pairs, unreduced.
+///
+/// ```
+/// use sqrt_irrational::prelude::*;
+/// use sqrt_irrational::{FareySequence, ReifyRatioList};
+/// assert_eq!(<FareySequence<One> as ReifyRatioList>::to_vec(), vec![(0, 1), (1, 1)]);
+/// assert_eq!(<FareySequence<Two> as ReifyRatioList>::to_vec(), vec![(0, 1), (1, 2), (1, 1)]);
+/// assert_eq!(<FareySequence<Three> as ReifyRatioList>::to_vec(), vec![(0, 1), (1, 3), (1, 2), (2, 3), (1, 1)]);
+/// ```
+#[cfg(feature = "alloc")]
+pub trait ReifyRatioList {
+    fn to_vec() -> Vec<(u64, u64)>;
+}
+#[cfg(feature = "alloc")]
+impl ReifyRatioList for TNil {
+    fn to_vec() -> Vec<(u64, u64)> { Vec::new() }
+}
+#[cfg(feature = "alloc")]
+impl<H: ReifyRatio, T: ReifyRatioList> ReifyRatioList for TCons<H, T> {
+    fn to_vec() -> Vec<(u64, u64)> {
+        let mut v = vec![H::OUTPUT];
+        v.extend(T::to_vec());
+        v
+    }
+}
+
+/// Compare two ratios by cross multiplying, which works whether or not they're reduced.
+pub trait RatioCompare<R> {
+    type Output;
+}
+impl<A: Nat, B: Nat, C: Nat, D: Nat> RatioCompare<Ratio<C, D>> for Ratio<A, B> where
+    Ratio<A, B>: ValidRatio,
+    Ratio<C, D>: ValidRatio,
+    A: Product<D>,
+    C: Product<B>,
+    <A as Product<D>>::Output: Compare<<C as Product<B>>::Output>,
+{
+    type Output = <<A as Product<D>>::Output as Compare<<C as Product<B>>::Output>>::Output;
+}
+
+/// Rational equality, by cross multiplying.
+pub trait RatioEq<R> {
+    type Output;
+}
+impl<P: Nat, Q: Nat, R: Nat, S: Nat> RatioEq<Ratio<R, S>> for Ratio<P, Q> where
+    Ratio<P, Q>: ValidRatio,
+    Ratio<R, S>: ValidRatio,
+    P: Product<S>,
+    R: Product<Q, Output = <P as Product<S>>::Output>,
+{
+    type Output = ();
+}
+
+/// Rational addition. This doesn't bother reducing the result.
+pub trait RatioAdd<R> {
+    type Output;
+}
+impl<P: Nat, Q: Nat, R: Nat, S: Nat> RatioAdd<Ratio<R, S>> for Ratio<P, Q> where
+    Ratio<P, Q>: ValidRatio,
+    Ratio<R, S>: ValidRatio,
+    P: Product<S>,
+    R: Product<Q>,
+    Q: Product<S>,
+    <R as Product<Q>>::Output: Nat,
+    <P as Product<S>>::Output: Sum<<R as Product<Q>>::Output>,
+    <<P as Product<S>>::Output as Sum<<R as Product<Q>>::Output>>::Output: Nat,
+    <Q as Product<S>>::Output: Nat,
+{
+    type Output = Ratio<<<P as Product<S>>::Output as Sum<<R as Product<Q>>::Output>>::Output, <Q as Product<S>>::Output>;
+}
+
+/// Since `Ratio`s aren't reduced, it isn't obvious that `RatioAdd` respects `RatioEq`.
+/// This checks that it does: if `p/q == p'/q'` and `r/s == r'/s'`, then
+/// `p/q + r/s == p'/q' + r'/s'`.
+///
+/// Both sides of the cross multiplication come out to `x(ss') + y(qq')`, where `x = pq'` and
+/// `y = rs'`. Getting there takes `ProductMonotone` to multiply out the sums, and
+/// `ProductCommutes` and `ProductExchange` to shuffle four factors around. Those lemmas are
+/// proved for all naturals, so this holds for every four ratios outright, except that the new
+/// denominators `qs` and `q's'` being positive is checked at each concrete ratio.
+pub trait RatioAddRespectsEq<A2, B, B2> {
+    type Proof;
+}
+impl<P, Q, P2, Q2, R, S, R2, S2, X, Y, PS, RQ, QS, PS2, RQ2, QS2, SS2, QQ2, N1, N2>
+    RatioAddRespectsEq<Ratio<P2, Q2>, Ratio<R, S>, Ratio<R2, S2>> for Ratio<P, Q> where
+    P: Nat, Q: Nat, P2: Nat, Q2: Nat, R: Nat, S: Nat, R2: Nat, S2: Nat,
+    X: Nat, Y: Nat, PS: Nat, RQ: Nat, QS: Nat, PS2: Nat, RQ2: Nat, QS2: Nat, SS2: Nat, QQ2: Nat,
+    N1: Nat, N2: Nat,
+    // Hypotheses: all four ratios are valid, `pq' = p'q = x` and `rs' = r's = y`.
+    Ratio<P, Q>: ValidRatio, Ratio<P2, Q2>: ValidRatio, Ratio<R, S>: ValidRatio, Ratio<R2, S2>: ValidRatio,
+    P: Product<Q2, Output = X>, P2: Product<Q, Output = X>,
+    R: Product<S2, Output = Y>, R2: Product<S, Output = Y>,
+    // Names for the pieces of the two sums, as `RatioAdd` computes them,
+    P: Product<S, Output = PS>, R: Product<Q, Output = RQ>, PS: Sum<RQ, Output = N1>, Q: Product<S, Output = QS>,
+    P2: Product<S2, Output = PS2>, R2: Product<Q2, Output = RQ2>, PS2: Sum<RQ2, Output = N2>, Q2: Product<S2, Output = QS2>,
+    // Both sums are valid ratios too, since products of positive numbers are positive.
+    QS: GreaterThan<Zero>, QS2: GreaterThan<Zero>,
+    // And names for a couple more products we'll need.
+    S: Product<S2, Output = SS2>, Q: Product<Q2, Output = QQ2>,
+    X: Product<SS2>, Y: Product<QQ2>,
+    <X as Product<SS2>>::Output: Nat + Sum<<Y as Product<QQ2>>::Output>,
+    <Y as Product<QQ2>>::Output: Nat,
+    // `s's = ss'` and `q'q = qq'`, by `ProductCommutes`.
+    S2: ProductCommutes<S> + Product<S, Output = SS2>,
+    Q2: ProductCommutes<Q> + Product<Q, Output = QQ2>,
+    // `(ps)(q's') = (pq')(ss')`, by `ProductExchange`,
+    S2: ProductExchange<P, S, Q2>,
+    PS: Product<QS2, Output = <<P as Product<Q2>>::Output as Product<<S as Product<S2>>::Output>>::Output>,
+    // and `(rq)(q's') = (rq)(s'q') = (rs')(qq')`, by `ProductCommutes` and `ProductExchange`.
+    Q2: ProductCommutes<S2> + ProductExchange<R, Q, S2>,
+    S2: Product<Q2, Output = QS2>,
+    RQ: Product<QS2, Output = <<R as Product<S2>>::Output as Product<<Q as Product<Q2>>::Output>>::Output>,
+    // `(p's')(qs) = (p'q)(s's)`, by `ProductExchange`,
+    S: ProductExchange<P2, S2, Q>,
+    PS2: Product<QS, Output = <<P2 as Product<Q>>::Output as Product<<S2 as Product<S>>::Output>>::Output>,
+    // and `(r'q')(qs) = (r'q')(sq) = (r's)(q'q)`, by `ProductCommutes` and `ProductExchange`.
+    S: ProductCommutes<Q> + Product<Q, Output = QS>,
+    Q: ProductExchange<R2, Q2, S>,
+    RQ2: Product<QS, Output = <<R2 as Product<S>>::Output as Product<<Q2 as Product<Q>>::Output>>::Output>,
+    // `(ps + rq)(q's') = (ps)(q's') + (rq)(q's')`, by `ProductMonotone`, and likewise on the
+    // other side.
+    QS2: ProductMonotone<PS, RQ>,
+    N1: Product<QS2, Output = <<PS as Product<QS2>>::Output as Sum<<RQ as Product<QS2>>::Output>>::Output>,
+    QS: ProductMonotone<PS2, RQ2>,
+    N2: Product<QS, Output = <<PS2 as Product<QS>>::Output as Sum<<RQ2 as Product<QS>>::Output>>::Output>,
+{
+    // Both sides of the cross multiplication come out to `x(ss') + y(qq')`.
+    type Proof = <<Ratio<P, Q> as RatioAdd<Ratio<R, S>>>::Output as RatioEq<<Ratio<P2, Q2> as RatioAdd<Ratio<R2, S2>>>::Output>>::Output;
+}
+
+/// Raise a ratio to a natural power, one half at a time.
+pub trait RatioPow<Exponent: Nat> {
+    type Output;
+}
+impl<N: Nat, D: Nat, E: Nat> RatioPow<E> for Ratio<N, D> where
+    Ratio<N, D>: ValidRatio,
+    N: Power<E>,
+    D: Power<E>,
+    <N as Power<E>>::Output: Nat,
+    <D as Power<E>>::Output: Nat,
+{
+    type Output = Ratio<<N as Power<E>>::Output, <D as Power<E>>::Output>;
+}
+
+/// Rational multiplication. This doesn't bother reducing the result either.
+pub trait RatioMul<R> {
+    type Output;
+}
+impl<P: Nat, Q: Nat, R: Nat, S: Nat> RatioMul<Ratio<R, S>> for Ratio<P, Q> where
+    Ratio<P, Q>: ValidRatio,
+    Ratio<R, S>: ValidRatio,
+    P: Product<R>,
+    Q: Product<S>,
+    <P as Product<R>>::Output: Nat,
+    <Q as Product<S>>::Output: Nat,
+{
+    type Output = Ratio<<P as Product<R>>::Output, <Q as Product<S>>::Output>;
+}
+
+/// Rational division. There's no impl for dividing by a zero numerator.
+pub trait RatioDiv<R> {
+    type Output;
+}
+impl<P: Nat, Q: Nat, R: Nat, S: Nat> RatioDiv<Ratio<Successor<R>, S>> for Ratio<P, Q> where
+    Ratio<P, Q>: ValidRatio,
+    Ratio<Successor<R>, S>: ValidRatio,
+    Ratio<P, Q>: RatioMul<Ratio<S, Successor<R>>>,
+{
+    type Output = <Ratio<P, Q> as RatioMul<Ratio<S, Successor<R>>>>::Output;
+}
+
+/// Reduce a ratio to lowest terms, by dividing through by the gcd of its terms.
+pub trait RatioSimplify {
+    type Output;
+}
+impl<N: Nat, D: Nat> RatioSimplify for Ratio<N, D> where
+    Ratio<N, D>: ValidRatio,
+    N: Gcd<D>,
+    N: Quotient<<N as Gcd<D>>::Output>,
+    D: Quotient<<N as Gcd<D>>::Output>,
+{
+    type Output = Ratio<<N as Quotient<<N as Gcd<D>>::Output>>::Output, <D as Quotient<<N as Gcd<D>>::Output>>::Output>;
+}
+
+/// Checks, one concrete ratio at a time, that `RatioSimplify` gives lowest terms, and that
+/// simplifying those again doesn't change them.
+///
+/// This only establishes idempotence. The simplified terms being coprime would need
+/// `gcd(n / g, d / g) = 1` for `g = gcd(n, d)`, and nothing here proves that for every ratio,
+/// so the `where` clause just asks for it. That only holds where rustc can work it out, at the
+/// ratios this is used at. Given it, simplifying again divides through by `1`, which does
+/// nothing by `QuotientOne`, and rustc checks that step for every ratio at once.
+pub trait CheckSimplifyCanonical {
+    type Coprime;
+    type Idempotent;
+}
+impl<R, N: Nat, D: Nat> CheckSimplifyCanonical for R where
+    R: RatioSimplify<Output = Ratio<N, D>>,
+    // The simplified terms have no factors in common,
+    N: Gcd<D, Output = One>,
+    // and there's still a positive denominator.
+    Ratio<N, D>: ValidRatio,
+    // `n / 1 = n` and `d / 1 = d`, by `QuotientOne`.
+    N: QuotientOne + Quotient<One, Output = N>,
+    D: QuotientOne + Quotient<One, Output = D>,
+{
+    type Coprime = <N as Coprime<D>>::Output;
+    type Idempotent = Equal<Ratio<N, D>, <Ratio<N, D> as RatioSimplify>::Output>;
+}
+
+/// Swap numerator and denominator. There's no impl for a zero numerator.
+pub trait Reciprocal {
+    type Output;
+}
+impl<N: Nat, D: Nat> Reciprocal for Ratio<Successor<N>, D> where Ratio<Successor<N>, D>: ValidRatio {
+    type Output = Ratio<D, Successor<N>>;
+}
+
+/// The continued fraction `[a₀; a₁, ..., aₖ]` of a ratio, as a list of its coefficients.
+///
+/// That's Euclid's algorithm again: `a₀` is `n / d`, and the rest
+/// is the continued fraction of `d / (n mod d)`, unless that's zero.
+pub trait ContinuedFraction {
+    type Output;
+}
+impl<N: Nat, D: Nat> ContinuedFraction for Ratio<N, D> where
+    Ratio<N, D>: ValidRatio,
+    N: Quotient<D>,
+    N: Remainder<D>,
+    <N as Remainder<D>>::Output: ContinuedFractionStep<D>,
+{
+    type Output = TCons<<N as Quotient<D>>::Output, <<N as Remainder<D>>::Output as ContinuedFractionStep<D>>::Output>;
+}
+// The rest of the coefficients, after a remainder of `Self`.
+pub trait ContinuedFractionStep<D> {
+    type Output;
+}
+impl<D> ContinuedFractionStep<D> for Zero {
+    type Output = TNil;
+}
+impl<D: Nat, R: Nat> ContinuedFractionStep<D> for Successor<R> where Ratio<D, Successor<R>>: ContinuedFraction {
+    type Output = <Ratio<D, Successor<R>> as ContinuedFraction>::Output;
+}
+
+/// Evaluate a list of continued fraction coefficients back into a reduced ratio,
+/// working out from the last one: `[a₀; a₁, ...] = a₀ + 1/[a₁; ...]`.
+pub trait FromContinuedFraction {
+    type Output;
+}
+impl<A: Nat> FromContinuedFraction for TCons<A, TNil> {
+    type Output = Ratio<A, One>;
+}
+impl<A: Nat, B, T> FromContinuedFraction for TCons<A, TCons<B, T>> where
+    TCons<B, T>: FromContinuedFraction,
+    <TCons<B, T> as FromContinuedFraction>::Output: Reciprocal,
+    Ratio<A, One>: RatioAdd<<<TCons<B, T> as FromContinuedFraction>::Output as Reciprocal>::Output>,
+    <Ratio<A, One> as RatioAdd<<<TCons<B, T> as FromContinuedFraction>::Output as Reciprocal>::Output>>::Output: RatioSimplify,
+{
+    type Output = <<Ratio<A, One> as RatioAdd<<<TCons<B, T> as FromContinuedFraction>::Output as Reciprocal>::Output>>::Output as RatioSimplify>::Output;
+}
+
+/// Taking a ratio to its continued fraction and back gives an equal ratio,
+/// and for a ratio already in lowest terms, the very same one.
+///
+/// This is checked per instance, and isn't a lemma: there's no induction over the coefficients
+/// behind it. The `where` clause asks rustc to expand and re-evaluate the continued fraction of
+/// the one ratio it's used at, and compare the result, so all it shows is that the round trip
+/// works for the ratios in the proofs below.
+pub trait ContinuedFractionRoundTrip {
+    type Equivalent;
+    type Canonical;
+}
+impl<R> ContinuedFractionRoundTrip for R where
+    R: ContinuedFraction,
+    <R as ContinuedFraction>::Output: FromContinuedFraction,
+    R: RatioEq<<<R as ContinuedFraction>::Output as FromContinuedFraction>::Output>,
+    R: RatioSimplify,
+    <R as RatioSimplify>::Output: Congruent<<<R as ContinuedFraction>::Output as FromContinuedFraction>::Output>,
+{
+    type Equivalent = <R as RatioEq<<<R as ContinuedFraction>::Output as FromContinuedFraction>::Output>>::Output;
+    type Canonical = Equal<<<R as ContinuedFraction>::Output as FromContinuedFraction>::Output, <R as RatioSimplify>::Output>;
+}
+
+/// The convergents of a continued fraction, `[a₀]`, `[a₀; a₁]`, ..., `[a₀; a₁, ..., aₖ]`,
+/// as a list of ratios.
+///
+/// They come from `pᵢ = aᵢpᵢ₋₁ + pᵢ₋₂` and `qᵢ = aᵢqᵢ₋₁ + qᵢ₋₂`, starting from `0/1` and `1/0`,
+/// and come out in lowest terms without any simplifying.
+pub trait Convergents {
+    type Output;
+}
+impl<L> Convergents for L where L: ConvergentsFrom<One, Zero, Zero, One> {
+    type Output = <L as ConvergentsFrom<One, Zero, Zero, One>>::Output;
+}
+// The convergents for the rest of the coefficients, given the last two, `P1/Q1` and `P2/Q2`.
+pub trait ConvergentsFrom<P1, Q1, P2, Q2> {
+    type Output;
+}
+impl<P1, Q1, P2, Q2> ConvergentsFrom<P1, Q1, P2, Q2> for TNil {
+    type Output = TNil;
+}
+impl<A: Nat, T, P1: Nat, Q1: Nat, P2: Nat, Q2: Nat> ConvergentsFrom<P1, Q1, P2, Q2> for TCons<A, T> where
+    A: Product<P1> + Product<Q1>,
+    <A as Product<P1>>::Output: Sum<P2>,
+    <A as Product<Q1>>::Output: Sum<Q2>,
+    NextTerm<A, P1, P2>: Nat,
+    NextTerm<A, Q1, Q2>: Nat,
+    T: ConvergentsFrom<NextTerm<A, P1, P2>, NextTerm<A, Q1, Q2>, P1, Q1>,
+{
+    type Output = TCons<
+        Ratio<NextTerm<A, P1, P2>, NextTerm<A, Q1, Q2>>,
+        <T as ConvergentsFrom<NextTerm<A, P1, P2>, NextTerm<A, Q1, Q2>, P1, Q1>>::Output,
+    >;
+}
+// `a·x₁ + x₂`.
+type NextTerm<A, X1, X2> = <<A as Product<X1>>::Output as Sum<X2>>::Output;
+
+/// The closest ratio to `Self` with a denominator of at most `MaxDenominator`, which
+/// has to be at least one.
+///
+/// This walks the convergents until the next one's denominator would be too big. The
+/// answer is then either the last convergent `pₖ/qₖ` that fit, or the last semiconvergent
+/// `(pₖ₋₁ + j·pₖ)/(qₖ₋₁ + j·qₖ)` that fits, with `j` as big as it can be. Those are on either
+/// side of `Self`, and whichever is closer wins, the convergent if they tie. That's the same
+/// answer Python's `Fraction.limit_denominator` gives.
+pub trait BestApproximation<MaxDenominator: Nat> {
+    type Output;
+}
+impl<X: Nat, Y: Nat, N: Nat> BestApproximation<N> for Ratio<X, Y> where
+    Ratio<X, Y>: ContinuedFraction,
+    <Ratio<X, Y> as ContinuedFraction>::Output: LimitDenominator<Ratio<X, Y>, N, One, Zero, Zero, One>,
+{
+    type Output = <<Ratio<X, Y> as ContinuedFraction>::Output as LimitDenominator<Ratio<X, Y>, N, One, Zero, Zero, One>>::Output;
+}
+// Like `ConvergentsFrom`, but stopping before a denominator goes over `N`.
+pub trait LimitDenominator<Target, N, P1, Q1, P2, Q2> {
+    type Output;
+}
+impl<Target, N, P1: Nat, Q1: Nat, P2, Q2> LimitDenominator<Target, N, P1, Q1, P2, Q2> for TNil {
+    // Every convergent fit, so the last one is `Target` itself.
+    type Output = Ratio<P1, Q1>;
+}
+impl<A: Nat, T, Target, N: Nat, P1: Nat, Q1: Nat, P2: Nat, Q2: Nat> LimitDenominator<Target, N, P1, Q1, P2, Q2> for TCons<A, T> where
+    A: Product<Q1>,
+    <A as Product<Q1>>::Output: Sum<Q2>,
+    NextTerm<A, Q1, Q2>: Compare<N>,
+    <NextTerm<A, Q1, Q2> as Compare<N>>::Output: SelectOrd<True, True, False>,
+    TCons<A, T>: LimitDenominatorStep<Target, N, P1, Q1, P2, Q2, <<NextTerm<A, Q1, Q2> as Compare<N>>::Output as SelectOrd<True, True, False>>::Output>,
+{
+    type Output = <TCons<A, T> as LimitDenominatorStep<
+        Target, N, P1, Q1, P2, Q2,
+        <<NextTerm<A, Q1, Q2> as Compare<N>>::Output as SelectOrd<True, True, False>>::Output,
+    >>::Output;
+}
+pub trait LimitDenominatorStep<Target, N, P1, Q1, P2, Q2, Fits> {
+    type Output;
+}
+impl<A: Nat, T, Target, N, P1: Nat, Q1: Nat, P2: Nat, Q2: Nat> LimitDenominatorStep<Target, N, P1, Q1, P2, Q2, True> for TCons<A, T> where
+    A: Product<P1> + Product<Q1>,
+    <A as Product<P1>>::Output: Sum<P2>,
+    <A as Product<Q1>>::Output: Sum<Q2>,
+    T: LimitDenominator<Target, N, NextTerm<A, P1, P2>, NextTerm<A, Q1, Q2>, P1, Q1>,
+{
+    type Output = <T as LimitDenominator<Target, N, NextTerm<A, P1, P2>, NextTerm<A, Q1, Q2>, P1, Q1>>::Output;
+}
+impl<A, T, Target, N: Nat, P1: Nat, Q1: Nat, P2: Nat, Q2: Nat> LimitDenominatorStep<Target, N, P1, Q1, P2, Q2, False> for TCons<A, T> where
+    N: Difference<Q2>,
+    <N as Difference<Q2>>::Output: Quotient<Q1>,
+    SemiJ<N, Q1, Q2>: Product<P1> + Product<Q1>,
+    <SemiJ<N, Q1, Q2> as Product<P1>>::Output: Sum<P2>,
+    <SemiJ<N, Q1, Q2> as Product<Q1>>::Output: Sum<Q2>,
+    NextTerm<SemiJ<N, Q1, Q2>, P1, P2>: Nat,
+    NextTerm<SemiJ<N, Q1, Q2>, Q1, Q2>: Nat,
+    Ratio<P1, Q1>: CloserTo<Target, Ratio<NextTerm<SemiJ<N, Q1, Q2>, P1, P2>, NextTerm<SemiJ<N, Q1, Q2>, Q1, Q2>>>,
+{
+    type Output = <Ratio<P1, Q1> as CloserTo<Target, Ratio<NextTerm<SemiJ<N, Q1, Q2>, P1, P2>, NextTerm<SemiJ<N, Q1, Q2>, Q1, Q2>>>>::Output;
+}
+// `j = (n - qₖ₋₁) / qₖ`, the most `qₖ`s that fit on top of `qₖ₋₁`.
+type SemiJ<N, Q1, Q2> = <<N as Difference<Q2>>::Output as Quotient<Q1>>::Output;
+
+/// Whichever of `Self` and `Other` is closer to `Target`, or `Self` if they're as close.
+pub trait CloserTo<Target, Other> {
+    type Output;
+}
+// `|u₁/v₁ - x/y|` against `|u₂/v₂ - x/y|` is `|u₁y - xv₁|·v₂` against `|u₂y - xv₂|·v₁`.
+impl<X: Nat, Y: Nat, U1: Nat, V1: Nat, U2: Nat, V2: Nat> CloserTo<Ratio<X, Y>, Ratio<U2, V2>> for Ratio<U1, V1> where
+    U1: Product<Y>,
+    X: Product<V1> + Product<V2>,
+    U2: Product<Y>,
+    <U1 as Product<Y>>::Output: SignedDifference<<X as Product<V1>>::Output>,
+    <U2 as Product<Y>>::Output: SignedDifference<<X as Product<V2>>::Output>,
+    <<U1 as Product<Y>>::Output as SignedDifference<<X as Product<V1>>::Output>>::Output: IntAbs,
+    <<U2 as Product<Y>>::Output as SignedDifference<<X as Product<V2>>::Output>>::Output: IntAbs,
+    Distance<U1, V1, X, Y>: Product<V2>,
+    Distance<U2, V2, X, Y>: Product<V1>,
+    <Distance<U1, V1, X, Y> as Product<V2>>::Output: Compare<<Distance<U2, V2, X, Y> as Product<V1>>::Output>,
+    <<Distance<U1, V1, X, Y> as Product<V2>>::Output as Compare<<Distance<U2, V2, X, Y> as Product<V1>>::Output>>::Output:
+        SelectOrd<Ratio<U1, V1>, Ratio<U1, V1>, Ratio<U2, V2>>,
+{
+    type Output = <<<Distance<U1, V1, X, Y> as Product<V2>>::Output as Compare<<Distance<U2, V2, X, Y> as Product<V1>>::Output>>::Output
+        as SelectOrd<Ratio<U1, V1>, Ratio<U1, V1>, Ratio<U2, V2>>>::Output;
+}
+// `|uy - xv|`.
+type Distance<U, V, X, Y> = <<<U as Product<Y>>::Output as SignedDifference<<X as Product<V>>::Output>>::Output as IntAbs>::Output;
+
+/// Approximate the square root of `Self` with Newton's method, `x ↦ (x + r/x)/2`,
+/// starting from `1` and stopping after `Iterations` steps.
+///
+/// Nothing gets reduced along the way, so the numbers involved grow quickly.
+pub trait RatioSqrtFloor<Iterations: Nat> {
+    type Output;
+}
+impl<N: Nat, D: Nat> RatioSqrtFloor<Zero> for Ratio<N, D> where Ratio<N, D>: ValidRatio {
+    type Output = Ratio<One, One>;
+}
+impl<N: Nat, D: Nat, K: Nat> RatioSqrtFloor<Successor<K>> for Ratio<N, D> where
+    Ratio<N, D>: RatioSqrtFloor<K>,
+    <Ratio<N, D> as RatioSqrtFloor<K>>::Output: NewtonStep<Ratio<N, D>>,
+{
+    type Output = <<Ratio<N, D> as RatioSqrtFloor<K>>::Output as NewtonStep<Ratio<N, D>>>::Output;
+}
+/// One step of Newton's method for the square root of `R`, from the guess `Self`.
+pub trait NewtonStep<R> {
+    type Output;
+}
+impl<X, R> NewtonStep<R> for X where
+    R: RatioDiv<X>,
+    X: RatioAdd<<R as RatioDiv<X>>::Output>,
+    <X as RatioAdd<<R as RatioDiv<X>>::Output>>::Output: RatioMul<Ratio<One, Two>>,
+{
+    type Output = <<X as RatioAdd<<R as RatioDiv<X>>::Output>>::Output as RatioMul<Ratio<One, Two>>>::Output;
+}
+
+/// A rational with a sign, carried on the numerator.
+/// Like `Ratio`, it isn't reduced, and `-0/d` is as good as `+0/d`.
+pub struct SignedRatio<Numerator: Int, Denominator: Nat> { _a: PhantomData<Numerator>, _b: PhantomData<Denominator> }
+
+pub trait ReifySignedRatio {
+    const OUTPUT: (i64, u64);
+    fn val(&self) -> (i64, u64) { Self::OUTPUT }
+}
+impl<N: Int + ReifyInt, D: Nat + Reify> ReifySignedRatio for SignedRatio<N, D> {
+    const OUTPUT: (i64, u64) = (N::OUTPUT, D::OUTPUT);
+}
+
+/// The absolute value of a rational, as a plain `Ratio`.
+pub trait RatioAbs {
+    type Output;
+}
+impl<N: Nat, D: Nat> RatioAbs for Ratio<N, D> where Ratio<N, D>: ValidRatio {
+    type Output = Ratio<N, D>;
+}
+impl<N: Int + IntAbs, D: Nat> RatioAbs for SignedRatio<N, D> where Ratio<<N as IntAbs>::Output, D>: ValidRatio {
+    type Output = Ratio<<N as IntAbs>::Output, D>;
+}
+
+/// Compare two rationals, signed or not, by their absolute values.
+pub trait RatioCompareMagnitude<R> {
+    type Output;
+}
+impl<A, B> RatioCompareMagnitude<B> for A where
+    A: RatioAbs,
+    B: RatioAbs,
+    <A as RatioAbs>::Output: RatioCompare<<B as RatioAbs>::Output>,
+{
+    type Output = <<A as RatioAbs>::Output as RatioCompare<<B as RatioAbs>::Output>>::Output;
+}
+
+/// Insert a ratio into an ascending list of ratios, dropping it if an equal one is already there.
+pub trait RatioInsert<R> {
+    type Output;
+}
+impl<R> RatioInsert<R> for TNil {
+    type Output = TCons<R, TNil>;
+}
+impl<R, H, T> RatioInsert<R> for TCons<H, T> where
+    R: RatioCompare<H>,
+    TCons<H, T>: RatioInsertStep<R, <R as RatioCompare<H>>::Output>,
+{
+    type Output = <TCons<H, T> as RatioInsertStep<R, <R as RatioCompare<H>>::Output>>::Output;
+}
+pub trait RatioInsertStep<R, Ordering> {
+    type Output;
+}
+impl<R, H, T> RatioInsertStep<R, Less> for TCons<H, T> {
+    type Output = TCons<R, TCons<H, T>>;
+}
+impl<R, H, T> RatioInsertStep<R, EqualOrd> for TCons<H, T> {
+    type Output = TCons<H, T>;
+}
+impl<R, H, T> RatioInsertStep<R, Greater> for TCons<H, T> where T: RatioInsert<R> {
+    type Output = TCons<H, <T as RatioInsert<R>>::Output>;
+}
+
+/// The Farey sequence of a given order: every reduced fraction in `[0, 1]`
+/// whose denominator is at most `Order`, in ascending order.
+///
+/// `Self` is the sorted list the fractions get inserted into, which
+/// is just `TNil` when you want the sequence on its own.
+pub trait Farey<Order: Nat> {
+    type Output;
+}
+impl<L> Farey<Zero> for L {
+    type Output = L;
+}
+impl<L, N: Nat> Farey<Successor<N>> for L where
+    L: Farey<N>,
+    <L as Farey<N>>::Output: FareyRow<Successor<N>, Successor<N>>,
+{
+    type Output = <<L as Farey<N>>::Output as FareyRow<Successor<N>, Successor<N>>>::Output;
+}
+pub type FareySequence<Order> = <TNil as Farey<Order>>::Output;
+
+// Inserts `K/D`, `(K - 1)/D`, ..., `0/D`, skipping the ones that aren't reduced.
+pub trait FareyRow<D: Nat, K: Nat> {
+    type Output;
+}
+impl<L, D: Nat> FareyRow<D, Zero> for L where
+    Zero: IsCoprime<D>,
+    L: RatioInsertIf<Ratio<Zero, D>, <Zero as IsCoprime<D>>::Output>,
+{
+    type Output = <L as RatioInsertIf<Ratio<Zero, D>, <Zero as IsCoprime<D>>::Output>>::Output;
+}
+impl<L, D: Nat, K: Nat> FareyRow<D, Successor<K>> for L where
+    Successor<K>: IsCoprime<D>,
+    L: RatioInsertIf<Ratio<Successor<K>, D>, <Successor<K> as IsCoprime<D>>::Output>,
+    <L as RatioInsertIf<Ratio<Successor<K>, D>, <Successor<K> as IsCoprime<D>>::Output>>::Output: FareyRow<D, K>,
+{
+    type Output = <<L as RatioInsertIf<Ratio<Successor<K>, D>, <Successor<K> as IsCoprime<D>>::Output>>::Output as FareyRow<D, K>>::Output;
+}
+pub trait RatioInsertIf<R, Condition> {
+    type Output;
+}
+impl<L: RatioInsert<R>, R> RatioInsertIf<R, True> for L {
+    type Output = <L as RatioInsert<R>>::Output;
+}
+impl<L, R> RatioInsertIf<R, False> for L {
+    type Output = L;
+}
+
+// There's nothing to be done with a zero denominator:
+// proof! { <Ratio<One, Zero> as RatioAdd<Ratio<One, Two>>>::Output }
+// proof! { <Ratio<Zero, Zero> as RatioEq<Ratio<Zero, Zero>>>::Output }
+// proof! { <Ratio<Zero, Two> as Reciprocal>::Output }
+// Nor with a zero denominator to approximate by:
+// proof! { <Ratio<Nine, Seven> as BestApproximation<Zero>>::Output }
+proofs! {
+    // Rational equality and addition tests:
+    <Ratio<One, Two> as RatioEq<Ratio<Three, Six>>>::Output;
+    <Ratio<Zero, One> as RatioEq<Ratio<Zero, Four>>>::Output;
+    Equal<Ratio<Five, Six>, <Ratio<One, Two> as RatioAdd<Ratio<One, Three>>>::Output>;
+    Equal<Ratio<Four, Four>, <Ratio<One, Two> as RatioAdd<Ratio<One, Two>>>::Output>;
+    <<Ratio<One, Two> as RatioAdd<Ratio<One, Two>>>::Output as RatioEq<Ratio<One, One>>>::Output;
+
+    // `RatioAdd` respects `RatioEq`:
+    <Ratio<One, One> as RatioAddRespectsEq<Ratio<Two, Two>, Ratio<One, Two>, Ratio<One, Two>>>::Proof;
+    <Ratio<Zero, One> as RatioAddRespectsEq<Ratio<Zero, Three>, Ratio<One, Two>, Ratio<Two, Four>>>::Proof;
+    <Ratio<Two, Three> as RatioAddRespectsEq<Ratio<Two, Three>, Ratio<One, One>, Ratio<Two, Two>>>::Proof;
+
+    // Rational exponentiation tests:
+    Equal<Ratio<One, One>, <Ratio<Two, Three> as RatioPow<Zero>>::Output>;
+    Equal<Ratio<Two, Three>, <Ratio<Two, Three> as RatioPow<One>>::Output>;
+    Equal<Ratio<Four, Nine>, <Ratio<Two, Three> as RatioPow<Two>>::Output>;
+    Equal<Ratio<One, Eight>, <Ratio<One, Two> as RatioPow<Three>>::Output>;
+
+    // Rational multiplication and division tests:
+    Equal<Ratio<Two, Six>, <Ratio<One, Two> as RatioMul<Ratio<Two, Three>>>::Output>;
+    Equal<Ratio<Three, Four>, <Ratio<One, Two> as RatioDiv<Ratio<Two, Three>>>::Output>;
+
+    // Simplification tests:
+    Equal<Ratio<One, Two>, <Ratio<Two, Four> as RatioSimplify>::Output>;
+    Equal<Ratio<Two, Three>, <Ratio<Six, Nine> as RatioSimplify>::Output>;
+    Equal<Ratio<Zero, One>, <Ratio<Zero, Five> as RatioSimplify>::Output>;
+    Equal<Ratio<Three, Seven>, <Ratio<Three, Seven> as RatioSimplify>::Output>;
+    <Ratio<Six, Nine> as CheckSimplifyCanonical>::Coprime;
+    <Ratio<Six, Nine> as CheckSimplifyCanonical>::Idempotent;
+    <Ratio<Four, Four> as CheckSimplifyCanonical>::Idempotent;
+    <Ratio<Zero, Eight> as CheckSimplifyCanonical>::Coprime;
+    <Ratio<Three, Seven> as CheckSimplifyCanonical>::Idempotent;
+
+    // Continued fractions, and back again:
+    Equal<Ratio<Three, Two>, <Ratio<Two, Three> as Reciprocal>::Output>;
+    Equal<TCons<Two, TCons<Three, TNil>>, <Ratio<Seven, Three> as ContinuedFraction>::Output>;
+    Equal<TCons<Zero, TCons<One, TCons<One, TCons<One, TCons<Two, TNil>>>>>, <Ratio<Five, Eight> as ContinuedFraction>::Output>;
+    Equal<TCons<Four, TNil>, <Ratio<Eight, Two> as ContinuedFraction>::Output>;
+    Equal<Ratio<Seven, Three>, <TCons<Two, TCons<Three, TNil>> as FromContinuedFraction>::Output>;
+    Equal<Ratio<Three, Two>, <TCons<One, TCons<One, TCons<One, TNil>>> as FromContinuedFraction>::Output>;
+    <Ratio<Seven, Three> as ContinuedFractionRoundTrip>::Canonical;
+    <Ratio<Five, Eight> as ContinuedFractionRoundTrip>::Canonical;
+    <Ratio<Six, Four> as ContinuedFractionRoundTrip>::Equivalent;
+    <Ratio<Six, Four> as ContinuedFractionRoundTrip>::Canonical;
+    <Ratio<Zero, Three> as ContinuedFractionRoundTrip>::Canonical;
+
+    // Convergents, and the best approximations they lead to. `17/12` is `[1; 2, 2, 2]`:
+    Equal<TCons<Ratio<One, One>, TCons<Ratio<Three, Two>, TCons<Ratio<Seven, Five>, TCons<SeventeenTwelfths, TNil>>>>,
+          <<SeventeenTwelfths as ContinuedFraction>::Output as Convergents>::Output>;
+    Equal<Ratio<One, One>, <SeventeenTwelfths as BestApproximation<One>>::Output>;
+    Equal<Ratio<Three, Two>, <SeventeenTwelfths as BestApproximation<Two>>::Output>;
+    Equal<Ratio<Three, Two>, <SeventeenTwelfths as BestApproximation<Four>>::Output>;
+    Equal<Ratio<Seven, Five>, <SeventeenTwelfths as BestApproximation<Five>>::Output>;
+    Equal<Ratio<Seven, Five>, <SeventeenTwelfths as BestApproximation<Six>>::Output>;
+    Equal<Ratio<<Five as Product<Two>>::Output, Seven>, <SeventeenTwelfths as BestApproximation<Nine>>::Output>;
+    Equal<SeventeenTwelfths, <SeventeenTwelfths as BestApproximation<<Six as Product<Two>>::Output>>::Output>;
+    // Sometimes a semiconvergent is closer, like `10/7` there. `9/7` is `[1; 3, 2]`, and `3/2` beats `1/1`:
+    Equal<Ratio<Three, Two>, <Ratio<Nine, Seven> as BestApproximation<Two>>::Output>;
+    Equal<Ratio<Four, Three>, <Ratio<Nine, Seven> as BestApproximation<Three>>::Output>;
+    Equal<Ratio<Two, One>, <Ratio<Seven, Three> as BestApproximation<One>>::Output>;
+
+    // Newton's method tests, which start at `1` and go `3/2`, `17/12`, ...:
+    Equal<Ratio<One, One>, <Ratio<Two, One> as RatioSqrtFloor<Zero>>::Output>;
+    Equal<Ratio<Three, Two>, <Ratio<Two, One> as RatioSqrtFloor<One>>::Output>;
+    Equal<Ratio<Five, Two>, <Ratio<Four, One> as RatioSqrtFloor<One>>::Output>;
+
+    Equal<Less, <Ratio<One, Three> as RatioCompare<Ratio<One, Two>>>::Output>;
+    Equal<EqualOrd, <Ratio<Two, Four> as RatioCompare<Ratio<One, Two>>>::Output>;
+
+    // Absolute values, and comparing by them:
+    Equal<Ratio<Three, Four>, <SignedRatio<Neg<Three>, Four> as RatioAbs>::Output>;
+    Equal<Ratio<Three, Four>, <SignedRatio<Pos<Three>, Four> as RatioAbs>::Output>;
+    Equal<Ratio<Three, Four>, <Ratio<Three, Four> as RatioAbs>::Output>;
+    Equal<Greater, <SignedRatio<Neg<Three>, Four> as RatioCompareMagnitude<SignedRatio<Pos<One>, Two>>>::Output>;
+    Equal<Less, <SignedRatio<Pos<One>, Three> as RatioCompareMagnitude<SignedRatio<Neg<One>, Two>>>::Output>;
+    Equal<EqualOrd, <SignedRatio<Neg<Two>, Four> as RatioCompareMagnitude<SignedRatio<Neg<One>, Two>>>::Output>;
+    Equal<EqualOrd, <SignedRatio<Neg<One>, Two> as RatioCompareMagnitude<Ratio<One, Two>>>::Output>;
+
+    // Picking between a ratio and a list, by comparing naturals:
+    Equal<Ratio<One, Two>, match_ord!(<One as Compare<Two>>::Output => {
+        Less => Ratio<One, Two>, EqualOrd => TNil, Greater => TCons<One, TCons<Two, TNil>>,
+    })>;
+    Equal<TCons<Two, TCons<One, TNil>>, match_ord!(<Two as Compare<One>>::Output => {
+        Less => Ratio<Two, One>, EqualOrd => TNil, Greater => TCons<Two, TCons<One, TNil>>,
+    })>;
+    Equal<Ratio<One, Four>, match_bool!(<Four as IsPrime>::Output => {
+        True => TNil, False => Ratio<One, Four>,
+    })>;
+
+    // Farey sequence tests:
+    Equal<FareySequence<One>, TCons<Ratio<Zero, One>, TCons<Ratio<One, One>, TNil>>>;
+    Equal<FareySequence<Two>,
+          TCons<Ratio<Zero, One>, TCons<Ratio<One, Two>, TCons<Ratio<One, One>, TNil>>>>;
+    Equal<FareySequence<Three>,
+          TCons<Ratio<Zero, One>, TCons<Ratio<One, Three>, TCons<Ratio<One, Two>,
+          TCons<Ratio<Two, Three>, TCons<Ratio<One, One>, TNil>>>>>>;
+}
+
+// Newton's method homes in on `sqrt(2)`, and fast.
+const fn distance_to_sqrt_two(x: f64) -> f64 {
+    let d = x - ::core::f64::consts::SQRT_2;
+    if d < 0.0 { -d } else { d }
+}
+const _: () = assert!(matches!(<<Ratio<Two, One> as RatioSqrtFloor<Two>>::Output as ReifyRatio>::OUTPUT, (17, 12)));
+const _: () = assert!(
+    distance_to_sqrt_two(<<Ratio<Two, One> as RatioSqrtFloor<One>>::Output as ReifyRatio>::AS_F64)
+        < distance_to_sqrt_two(<<Ratio<Two, One> as RatioSqrtFloor<Zero>>::Output as ReifyRatio>::AS_F64)
+);
+const _: () = assert!(
+    distance_to_sqrt_two(<<Ratio<Two, One> as RatioSqrtFloor<Two>>::Output as ReifyRatio>::AS_F64)
+        < distance_to_sqrt_two(<<Ratio<Two, One> as RatioSqrtFloor<One>>::Output as ReifyRatio>::AS_F64)
+);
+const _: () = assert!(distance_to_sqrt_two(<<Ratio<Two, One> as RatioSqrtFloor<Two>>::Output as ReifyRatio>::AS_F64) < 0.003);
+
+const _: () = assert!(matches!(<<Ratio<Six, Eight> as RatioSimplify>::Output as ReifyRatio>::OUTPUT, (3, 4)));
+const _: () = assert!(matches!(<<Ratio<Eight, Six> as RatioSimplify>::Output as ReifyRatio>::OUTPUT, (4, 3)));
+const _: () = assert!(matches!(<<Ratio<Nine, Nine> as RatioSimplify>::Output as ReifyRatio>::OUTPUT, (1, 1)));
+
+// Continued fractions round trip through reification too.
+const _: () = assert!(matches!(<<<Ratio<Five, Eight> as ContinuedFraction>::Output as FromContinuedFraction>::Output as ReifyRatio>::OUTPUT, (5, 8)));
+const _: () = assert!(matches!(<<<Ratio<Nine, Seven> as ContinuedFraction>::Output as FromContinuedFraction>::Output as ReifyRatio>::OUTPUT, (9, 7)));
+const _: () = assert!(matches!(<<<Ratio<Six, Four> as ContinuedFraction>::Output as FromContinuedFraction>::Output as ReifyRatio>::OUTPUT, (3, 2)));
+
+// Newton's second step for `sqrt(2)`, cut down to small denominators.
+type SeventeenTwelfths = <Ratio<Two, One> as RatioSqrtFloor<Two>>::Output;
+const _: () = assert!(matches!(<<SeventeenTwelfths as BestApproximation<Three>>::Output as ReifyRatio>::OUTPUT, (3, 2)));
+const _: () = assert!(matches!(<<SeventeenTwelfths as BestApproximation<Eight>>::Output as ReifyRatio>::OUTPUT, (10, 7)));
+const _: () = assert!(matches!(<<Ratio<Nine, Seven> as BestApproximation<Five>>::Output as ReifyRatio>::OUTPUT, (5, 4)));
+
+// `|-3/4|` is `3/4`.
+const _: () = assert!(matches!(<SignedRatio<Neg<Three>, Four> as ReifySignedRatio>::OUTPUT, (-3, 4)));
+const _: () = assert!(matches!(<<SignedRatio<Neg<Three>, Four> as RatioAbs>::Output as ReifyRatio>::OUTPUT, (3, 4)));
+
+pub type ThreeFourths = Ratio<Three, Four>;